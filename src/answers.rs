@@ -0,0 +1,368 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::Range;
+
+use sha2::{Digest, Sha256};
+use toml_edit::{Document, DocumentMut, Item, TableLike, value};
+
+use crate::{Answer, AocError, Part};
+
+/// Where the expected answers live unless told otherwise
+pub const MANIFEST_PATH: &str = "./data/answers.toml";
+
+const HASH_PREFIX: &str = "sha256:";
+
+/// Which input an expected answer belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Sample,
+    Real,
+}
+
+impl InputKind {
+    fn key(self) -> &'static str {
+        match self {
+            InputKind::Sample => "sample",
+            InputKind::Real => "input",
+        }
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.key())
+    }
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "one",
+        Part::Two => "two",
+    }
+}
+
+/// An expected answer, either as written or as a SHA-256 hash so the real answer doesn't
+/// have to be committed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Clear(String),
+    /// Lowercase hex digest of the answer
+    Hashed(String),
+}
+
+impl Expected {
+    pub fn hashed(answer: &str) -> Self {
+        let digest = Sha256::digest(answer.as_bytes());
+        Expected::Hashed(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    fn from_manifest(text: &str) -> Self {
+        match text.strip_prefix(HASH_PREFIX) {
+            Some(digest) => Expected::Hashed(digest.to_ascii_lowercase()),
+            None => Expected::Clear(text.to_owned()),
+        }
+    }
+
+    pub fn matches(&self, answer: &str) -> bool {
+        match self {
+            Expected::Clear(expected) => expected == answer,
+            Expected::Hashed(_) => *self == Expected::hashed(answer),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expected::Clear(answer) => write!(f, "{answer}"),
+            Expected::Hashed(digest) => write!(f, "{HASH_PREFIX}{digest}"),
+        }
+    }
+}
+
+/// How an answer compared with the manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// Nothing to compare against
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "missing",
+        })
+    }
+}
+
+/// Compare an answer with what was expected.  An unsolved part only passes if nothing was
+/// expected of it, in which case it's still missing.
+pub fn verdict(expected: Option<&Expected>, answer: &Answer) -> Verdict {
+    match (expected, answer) {
+        (None, _) => Verdict::Missing,
+        (Some(expected), Answer::Solved(answer)) if expected.matches(answer) => Verdict::Pass,
+        (Some(_), _) => Verdict::Fail,
+    }
+}
+
+/// The expected answers for every day, kept in a TOML file like
+///
+/// ```toml
+/// [day3]
+/// sample = { one = "357", two = "3121910778619" }
+/// input = { one = "sha256:..." }
+/// ```
+///
+/// Edits keep the file's existing comments and layout.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    path: String,
+    doc: DocumentMut,
+}
+
+impl Manifest {
+    /// Loads the manifest, treating a missing file as an empty one
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be read, or `AocError::Parse` if it isn't a
+    /// valid manifest
+    pub fn load(path: &str) -> Result<Self, AocError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_owned(),
+                    source,
+                });
+            }
+        };
+        let doc = Document::parse(text.as_str())
+            .map_err(|e| error_at(path, &text, e.span(), e.message().trim_end().to_owned()))?;
+        validate(&doc).map_err(|(span, reason)| error_at(path, &text, span, reason))?;
+        Ok(Manifest {
+            path: path.to_owned(),
+            doc: doc.into_mut(),
+        })
+    }
+
+    pub fn expected(&self, day: u8, kind: InputKind, part: Part) -> Option<Expected> {
+        self.doc
+            .get(&format!("day{day}"))?
+            .as_table_like()?
+            .get(kind.key())?
+            .as_table_like()?
+            .get(part_key(part))?
+            .as_str()
+            .map(Expected::from_manifest)
+    }
+
+    pub fn set(&mut self, day: u8, kind: InputKind, part: Part, expected: &Expected) {
+        let day = self
+            .doc
+            .entry(&format!("day{day}"))
+            .or_insert(toml_edit::table());
+        let answers = &mut day[kind.key()];
+        if answers.is_none() {
+            *answers = value(toml_edit::InlineTable::new());
+        }
+        answers[part_key(part)] = value(expected.to_string());
+    }
+
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be written
+    pub fn save(&self) -> Result<(), AocError> {
+        fs::write(&self.path, self.doc.to_string()).map_err(|source| AocError::Io {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+type Invalid = (Option<Range<usize>>, String);
+
+/// Check the layout up front, so typos get pointed out instead of quietly reading as missing
+fn validate(doc: &Document<&str>) -> Result<(), Invalid> {
+    fn table_like<'a>(
+        item: &'a Item,
+        span: Option<Range<usize>>,
+        what: &str,
+    ) -> Result<&'a dyn TableLike, Invalid> {
+        item.as_table_like()
+            .ok_or_else(|| (span, format!("Expected {what} to be a table")))
+    }
+    for (day_key, day) in doc.iter() {
+        let span = doc.key(day_key).and_then(|key| key.span());
+        let valid_day = day_key
+            .strip_prefix("day")
+            .and_then(|n| n.parse::<u8>().ok())
+            .is_some_and(|n| (1..=25).contains(&n));
+        if !valid_day {
+            return Err((span, format!("Expected a day like [day3], not [{day_key}]")));
+        }
+        let day = table_like(day, span, day_key)?;
+        for (kind_key, answers) in day.iter() {
+            let span = day.key(kind_key).and_then(|key| key.span());
+            if kind_key != "sample" && kind_key != "input" {
+                return Err((span, format!("Expected sample or input, not {kind_key}")));
+            }
+            let answers = table_like(answers, span, kind_key)?;
+            for (part_key, answer) in answers.iter() {
+                let span = answers.key(part_key).and_then(|key| key.span());
+                if part_key != "one" && part_key != "two" {
+                    return Err((span, format!("Expected one or two, not {part_key}")));
+                }
+                if !answer.is_str() {
+                    return Err((
+                        answer.span().or(span),
+                        format!("Answers must be strings, e.g. {part_key} = \"357\""),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn error_at(
+    path: &str,
+    text: &str,
+    span: Option<Range<usize>>,
+    reason: String,
+) -> AocError {
+    let offset = span.map_or(0, |span| span.start).min(text.len());
+    let before = &text[..offset];
+    let line_no = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let line = text[line_start..].lines().next().unwrap_or("");
+    let column = before[line_start..].chars().count() + 1;
+    AocError::parse(path, line_no, column, line, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DAYS;
+    use rstest::rstest;
+
+    fn temp_manifest(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("aoc_{name}_{}.toml", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path.to_str().unwrap().to_owned()
+    }
+
+    #[rstest]
+    fn test_hashed() {
+        let expected = Expected::hashed("357");
+        assert!(expected.matches("357"));
+        assert!(!expected.matches("358"));
+        // Round trips through the manifest's text form
+        assert_eq!(Expected::from_manifest(&expected.to_string()), expected);
+        assert_eq!(
+            Expected::hashed("abc").to_string(),
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[rstest]
+    #[case(Some(Expected::Clear("1".to_owned())), Answer::Solved("1".to_owned()), Verdict::Pass)]
+    #[case(Some(Expected::Clear("1".to_owned())), Answer::Solved("2".to_owned()), Verdict::Fail)]
+    #[case(Some(Expected::Clear("1".to_owned())), Answer::Unsolved, Verdict::Fail)]
+    #[case(None, Answer::Solved("2".to_owned()), Verdict::Missing)]
+    #[case(None, Answer::Unsolved, Verdict::Missing)]
+    fn test_verdict(
+        #[case] expected: Option<Expected>,
+        #[case] answer: Answer,
+        #[case] want: Verdict,
+    ) {
+        assert_eq!(verdict(expected.as_ref(), &answer), want);
+    }
+
+    #[rstest]
+    fn test_set_keeps_comments() {
+        let path = temp_manifest("set", "# Keep me\n[day3]\nsample = { one = \"357\" }\n");
+        let mut manifest = Manifest::load(&path).unwrap();
+        manifest.set(
+            3,
+            InputKind::Sample,
+            Part::Two,
+            &Expected::Clear("31".to_owned()),
+        );
+        manifest.set(4, InputKind::Real, Part::One, &Expected::hashed("13"));
+        manifest.save().unwrap();
+        let reloaded = Manifest::load(&path).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(text.starts_with("# Keep me\n"), "{text}");
+        assert_eq!(
+            reloaded.expected(3, InputKind::Sample, Part::One),
+            Some(Expected::Clear("357".to_owned()))
+        );
+        assert_eq!(
+            reloaded.expected(3, InputKind::Sample, Part::Two),
+            Some(Expected::Clear("31".to_owned()))
+        );
+        assert!(
+            reloaded
+                .expected(4, InputKind::Real, Part::One)
+                .unwrap()
+                .matches("13")
+        );
+        assert_eq!(reloaded.expected(4, InputKind::Sample, Part::One), None);
+    }
+
+    #[rstest]
+    fn test_missing_manifest_is_empty() {
+        let manifest = Manifest::load("./data/no_such_answers.toml").unwrap();
+        assert_eq!(manifest.expected(1, InputKind::Sample, Part::One), None);
+    }
+
+    #[rstest]
+    #[case("[day3]\nsample = { one = 357 }\n", 2, 18)]
+    #[case("[day3]\nexample = { one = \"357\" }\n", 2, 1)]
+    #[case("[day3]\nsample = { three = \"357\" }\n", 2, 12)]
+    #[case("[day30]\n", 1, 2)]
+    #[case("[day3]\nsample = \"357\"\n", 2, 1)]
+    #[case("[day3\n", 1, 6)]
+    fn test_invalid_manifest(#[case] contents: &str, #[case] line: usize, #[case] column: usize) {
+        let path = temp_manifest(&format!("invalid_{line}_{column}"), contents);
+        let result = Manifest::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        match result {
+            Err(AocError::Parse {
+                line: got_line,
+                column: got_column,
+                ..
+            }) => assert_eq!((got_line, got_column), (line, column)),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_sample_answers() {
+        // Every sample answer in the manifest should still be what the registry produces
+        let manifest = Manifest::load(MANIFEST_PATH).unwrap();
+        for day in &DAYS {
+            for part in [Part::One, Part::Two] {
+                let Some(expected) = manifest.expected(day.day, InputKind::Sample, part) else {
+                    continue;
+                };
+                let input = crate::Input::read(&day.sample_path(part)).unwrap();
+                let answer = day.parse_sample(&input).unwrap().solve(part);
+                assert_eq!(
+                    verdict(Some(&expected), &answer),
+                    Verdict::Pass,
+                    "Day {} Part {part}",
+                    day.day
+                );
+            }
+        }
+    }
+}
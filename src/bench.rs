@@ -0,0 +1,249 @@
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocError, BUILD_PROFILE, Part};
+
+/// Summary statistics over repeated runs of the same code, all in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    /// Panics if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Can't summarise zero samples");
+        let mut nanos: Vec<u64> = samples
+            .iter()
+            .map(|sample| u64::try_from(sample.as_nanos()).unwrap_or(u64::MAX))
+            .collect();
+        nanos.sort_unstable();
+        let runs = nanos.len();
+        let median = if runs % 2 == 1 {
+            nanos[runs / 2]
+        } else {
+            // Average the middle two, widening so it can't overflow
+            ((u128::from(nanos[runs / 2 - 1]) + u128::from(nanos[runs / 2])) / 2) as u64
+        };
+        // Nearest rank, so a p95 is always one of the samples
+        let p95 = nanos[(runs * 95).div_ceil(100) - 1];
+        let mean = nanos.iter().map(|&n| n as f64).sum::<f64>() / runs as f64;
+        let variance = nanos
+            .iter()
+            .map(|&n| (n as f64 - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+        Stats {
+            runs,
+            min_ns: nanos[0],
+            median_ns: median,
+            p95_ns: p95,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+/// Runs `f` `warmup` times without measuring, then `iterations` times measuring each run
+pub fn measure<T, F: FnMut() -> T>(warmup: usize, iterations: usize, mut f: F) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Which piece of a day was measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    PartOne,
+    /// Parsing part two's own input, for days with a separate part two sample
+    ParsePartTwo,
+    PartTwo,
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::PartOne,
+            Part::Two => Stage::PartTwo,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pad ourselves, so these line up in tables
+        let name = match self {
+            Stage::Parse => "Parse",
+            Stage::PartOne => "Part One",
+            Stage::ParsePartTwo => "Parse Part Two",
+            Stage::PartTwo => "Part Two",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// A set of measurements that later runs can be compared against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub profile: String,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn new(measurements: Vec<Measurement>) -> Self {
+        Baseline {
+            profile: BUILD_PROFILE.to_owned(),
+            measurements,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be read, or `AocError::Parse` if it isn't a
+    /// baseline
+    pub fn load(path: &str) -> Result<Self, AocError> {
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        serde_json::from_str(&text).map_err(|e| {
+            let line = text.lines().nth(e.line().saturating_sub(1)).unwrap_or("");
+            AocError::parse(path, e.line(), e.column(), line, e.to_string())
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be written
+    pub fn save(&self, path: &str) -> Result<(), AocError> {
+        // Serialising plain structs can't fail
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json + "\n").map_err(|source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn find(&self, day: u8, stage: Stage) -> Option<&Stats> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.stage == stage)
+            .map(|m| &m.stats)
+    }
+}
+
+/// How a measurement's median moved against the baseline, as a percentage (positive is slower)
+pub fn change_percent(current: &Stats, baseline: &Stats) -> f64 {
+    if baseline.median_ns == 0 {
+        return 0.0;
+    }
+    100.0 * (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[rstest]
+    fn test_stats() {
+        let stats = Stats::from_samples(&nanos(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 3);
+        assert_eq!(stats.p95_ns, 5);
+        assert_eq!(stats.mean_ns, 3);
+        assert!((stats.stddev_ns - 2.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[rstest]
+    #[case(&[10, 20], 15)]
+    #[case(&[1, 2], 1)]
+    #[case(&[3, 3], 3)]
+    #[case(&[u64::MAX, u64::MAX], u64::MAX)]
+    fn test_even_median(#[case] values: &[u64], #[case] want: u64) {
+        assert_eq!(Stats::from_samples(&nanos(values)).median_ns, want);
+    }
+
+    #[rstest]
+    fn test_p95_of_hundred() {
+        let values: Vec<u64> = (1..=100).collect();
+        assert_eq!(Stats::from_samples(&nanos(&values)).p95_ns, 95);
+    }
+
+    #[rstest]
+    fn test_measure_counts_runs() {
+        let mut calls = 0;
+        let stats = measure(3, 10, || calls += 1);
+        assert_eq!(calls, 13);
+        assert_eq!(stats.runs, 10);
+    }
+
+    #[rstest]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline::new(vec![Measurement {
+            day: 3,
+            stage: Stage::PartTwo,
+            stats: Stats::from_samples(&nanos(&[100, 200, 300])),
+        }]);
+        let path = std::env::temp_dir().join(format!("aoc_baseline_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        baseline.save(path).unwrap();
+        let loaded = Baseline::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.find(3, Stage::PartTwo).unwrap().median_ns, 200);
+        assert!(loaded.find(3, Stage::Parse).is_none());
+    }
+
+    #[rstest]
+    fn test_bad_baseline() {
+        let path =
+            std::env::temp_dir().join(format!("aoc_bad_baseline_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, "{\n  \"profile\": 3\n}\n").unwrap();
+        let result = Baseline::load(path);
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(result, Err(AocError::Parse { line: 2, .. })));
+    }
+
+    #[rstest]
+    #[case(100, 110, 10.0)]
+    #[case(100, 50, -50.0)]
+    #[case(0, 50, 0.0)]
+    fn test_change_percent(#[case] before: u64, #[case] after: u64, #[case] want: f64) {
+        let before = Stats::from_samples(&nanos(&[before]));
+        let after = Stats::from_samples(&nanos(&[after]));
+        assert!((change_percent(&after, &before) - want).abs() < 1e-9);
+    }
+}
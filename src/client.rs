@@ -0,0 +1,476 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use toml_edit::Document;
+
+use crate::AocError;
+use crate::answers::error_at;
+
+/// Where puzzles live unless told otherwise
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2025;
+
+/// Sent with every request, so the site's operators know who to get in touch with
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/twirrim/adventofcode2025)"
+);
+
+/// Everything that can go wrong talking to the puzzle site
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token in `AOC_SESSION` or the config file
+    NoSession,
+    /// The config file couldn't be read, or didn't make sense
+    Config(AocError),
+    /// The request never got an answer
+    Transport { url: String, source: ureq::Error },
+    /// The site answered, but not with success
+    Status { url: String, status: u16 },
+    /// A download couldn't be saved
+    Io { path: String, source: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "No session token, set AOC_SESSION or session in the config file"
+            ),
+            ClientError::Config(e) => write!(f, "{e}"),
+            ClientError::Transport { url, source } => write!(f, "Unable to reach {url}: {source}"),
+            ClientError::Status { url, status } => {
+                write!(f, "{url} answered with HTTP {status}")?;
+                // Most likely an expired token, which is worth spelling out
+                if *status == 400 || *status == 500 {
+                    write!(f, ", is the session token still valid?")?;
+                }
+                Ok(())
+            }
+            ClientError::Io { path, source } => write!(f, "Unable to write {path}: {source}"),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Config(source) => Some(source),
+            ClientError::Transport { source, .. } => Some(source),
+            ClientError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<AocError> for ClientError {
+    fn from(e: AocError) -> Self {
+        ClientError::Config(e)
+    }
+}
+
+/// Where to find the puzzle site and who to be when talking to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The `session` cookie from a logged in browser
+    pub session: Option<String>,
+    /// Without a trailing slash
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config file from `AOC_CONFIG`, or `~/.config/aoc/config.toml`, then lets
+    /// `AOC_SESSION` and `AOC_BASE_URL` override what it says
+    ///
+    /// # Errors
+    ///
+    /// As `Config::read`
+    pub fn load() -> Result<Self, AocError> {
+        Self::load_with(|name| env::var(name).ok())
+    }
+
+    /// As `load`, but looking up environment variables with `var`
+    ///
+    /// # Errors
+    ///
+    /// As `Config::read`
+    pub fn load_with<F>(var: F) -> Result<Self, AocError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        // Treat empty variables as unset, as InputSource does
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+        let path = var("AOC_CONFIG")
+            .or_else(|| var("HOME").map(|home| format!("{home}/.config/aoc/config.toml")));
+        let mut config = match path {
+            Some(path) => Self::read(&path)?,
+            None => Config::default(),
+        };
+        if let Some(session) = var("AOC_SESSION") {
+            config.session = Some(session.trim().to_owned());
+        }
+        if let Some(base_url) = var("AOC_BASE_URL") {
+            config.base_url = base_url.trim_end_matches('/').to_owned();
+        }
+        Ok(config)
+    }
+
+    /// Reads a config file like
+    ///
+    /// ```toml
+    /// session = "53616c7465645f5f..."
+    /// base_url = "https://adventofcode.com"
+    /// ```
+    ///
+    /// where both keys are optional, and a missing file is the same as an empty one
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be read, or `AocError::Parse` if it isn't a
+    /// valid config file
+    pub fn read(path: &str) -> Result<Self, AocError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_owned(),
+                    source,
+                });
+            }
+        };
+        let doc = Document::parse(text.as_str())
+            .map_err(|e| error_at(path, &text, e.span(), e.message().trim_end().to_owned()))?;
+        let mut config = Config::default();
+        for (key, item) in doc.iter() {
+            let span = doc.key(key).and_then(|key| key.span());
+            if key != "session" && key != "base_url" {
+                let reason = format!("Expected session or base_url, not {key}");
+                return Err(error_at(path, &text, span, reason));
+            }
+            let Some(value) = item.as_str() else {
+                let reason = format!("Expected {key} to be a string, e.g. {key} = \"...\"");
+                return Err(error_at(path, &text, item.span().or(span), reason));
+            };
+            if key == "session" {
+                config.session = Some(value.trim().to_owned());
+            } else {
+                config.base_url = value.trim().trim_end_matches('/').to_owned();
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Talks to the puzzle site with the session from a `Config`
+#[derive(Debug)]
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+/// Where a day's input came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already on disk, so nothing was downloaded
+    Cached,
+    /// It was downloaded and saved, this many bytes of it
+    Downloaded(usize),
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            // Statuses are checked by hand, so errors can say which address failed
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client { config, agent }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The address of `path` under this year's puzzles, like `/day/3/input`
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{YEAR}{path}", self.config.base_url)
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        let session = self.config.session.as_ref().ok_or(ClientError::NoSession)?;
+        Ok(format!("session={session}"))
+    }
+
+    /// Fetches `path` under this year's puzzles
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError` if there's no session token or the request doesn't succeed
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let cookie = self.cookie()?;
+        let response = self.agent.get(&url).header("Cookie", cookie).call();
+        read_body(url, response)
+    }
+
+    /// Posts `form` to `path` under this year's puzzles
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError` if there's no session token or the request doesn't succeed
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let cookie = self.cookie()?;
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", cookie)
+            .send_form(form.iter().copied());
+        read_body(url, response)
+    }
+
+    /// Downloads a day's input to `path`, unless it's already there.  Inputs never change, so
+    /// an existing file is always trusted, and nothing is written unless the whole input
+    /// arrived.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError` if the download fails or can't be saved
+    pub fn fetch_input(&self, day: u8, path: &str) -> Result<Fetched, ClientError> {
+        if Path::new(path).exists() {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.get(&format!("/day/{day}/input"))?;
+        let io_error = |source| ClientError::Io {
+            path: path.to_owned(),
+            source,
+        };
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        // Written alongside and then moved into place, so an interrupted write can't leave
+        // half an input that looks cached
+        let partial = format!("{path}.partial");
+        fs::write(&partial, &input).map_err(io_error)?;
+        fs::rename(&partial, path).map_err(io_error)?;
+        Ok(Fetched::Downloaded(input.len()))
+    }
+}
+
+fn read_body(
+    url: String,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, ClientError> {
+    let mut response = match response {
+        Ok(response) => response,
+        Err(source) => return Err(ClientError::Transport { url, source }),
+    };
+    let status = response.status().as_u16();
+    if !(200..300).contains(&status) {
+        return Err(ClientError::Status { url, status });
+    }
+    response
+        .body_mut()
+        .read_to_string()
+        .map_err(|source| ClientError::Transport { url, source })
+}
+
+/// A stand-in for the puzzle site, answering each connection with the next canned response
+/// and keeping the requests it got
+#[cfg(test)]
+pub(crate) struct MockServer {
+    pub url: String,
+    handle: std::thread::JoinHandle<Vec<String>>,
+}
+
+#[cfg(test)]
+impl MockServer {
+    pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                let response = format!(
+                    "HTTP/1.1 {status} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        MockServer { url, handle }
+    }
+
+    /// Every request received, once all the responses have been used up
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+
+    pub fn config(&self) -> Config {
+        Config {
+            session: Some("abc123".to_owned()),
+            base_url: self.url.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn temp_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("aoc_{name}_{}", std::process::id()));
+        path.to_str().unwrap().to_owned()
+    }
+
+    #[rstest]
+    fn test_config_read() {
+        let path = temp_path("config.toml");
+        fs::write(
+            &path,
+            "session = \" abc123\\n\"\nbase_url = \"http://localhost/\"\n",
+        )
+        .unwrap();
+        let config = Config::read(&path).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost");
+
+        fs::write(&path, "session = \"abc\"\ntoken = \"abc\"\n").unwrap();
+        match Config::read(&path) {
+            Err(AocError::Parse { line, reason, .. }) => assert_eq!(
+                (line, reason.as_str()),
+                (2, "Expected session or base_url, not token")
+            ),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Config::read(&path).unwrap(), Config::default());
+    }
+
+    #[rstest]
+    fn test_config_environment() {
+        let path = temp_path("env_config.toml");
+        fs::write(&path, "session = \"from_file\"\n").unwrap();
+        let vars = |overrides: &'static [(&'static str, &'static str)]| {
+            let path = path.clone();
+            move |name: &str| match name {
+                "AOC_CONFIG" => Some(path.clone()),
+                _ => overrides
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string()),
+            }
+        };
+        let config = Config::load_with(vars(&[])).unwrap();
+        assert_eq!(config.session.as_deref(), Some("from_file"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        let config = Config::load_with(vars(&[
+            ("AOC_SESSION", "from_env"),
+            ("AOC_BASE_URL", "http://127.0.0.1:1/"),
+        ]))
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("from_env"));
+        assert_eq!(config.base_url, "http://127.0.0.1:1");
+        // Empty variables are ignored
+        let config = Config::load_with(vars(&[("AOC_SESSION", "")])).unwrap();
+        assert_eq!(config.session.as_deref(), Some("from_file"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[rstest]
+    fn test_fetch_input() {
+        let server = MockServer::start(vec![(200, "1,2\n3,4\n")]);
+        let client = Client::new(server.config());
+        let path = temp_path("fetch/day3.txt");
+        let _ = fs::remove_dir_all(Path::new(&path).parent().unwrap());
+
+        assert_eq!(
+            client.fetch_input(3, &path).unwrap(),
+            Fetched::Downloaded(8)
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2\n3,4\n");
+        // Already there, so the server isn't asked again
+        assert_eq!(client.fetch_input(3, &path).unwrap(), Fetched::Cached);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1\r\n"));
+        let request = requests[0].to_ascii_lowercase();
+        assert!(request.contains("cookie: session=abc123\r\n"));
+        assert!(request.contains(&format!(
+            "user-agent: {}\r\n",
+            USER_AGENT.to_ascii_lowercase()
+        )));
+        fs::remove_dir_all(Path::new(&path).parent().unwrap()).unwrap();
+    }
+
+    #[rstest]
+    fn test_fetch_failure_leaves_nothing_behind() {
+        let server = MockServer::start(vec![(404, "Please don't repeatedly request this")]);
+        let client = Client::new(server.config());
+        let path = temp_path("day25_missing.txt");
+        match client.fetch_input(25, &path) {
+            Err(ClientError::Status { status, .. }) => assert_eq!(status, 404),
+            other => panic!("Expected a status error, got {other:?}"),
+        }
+        assert!(!Path::new(&path).exists());
+        server.requests();
+    }
+
+    #[rstest]
+    fn test_no_session() {
+        let client = Client::new(Config::default());
+        let path = temp_path("day1_no_session.txt");
+        assert!(matches!(
+            client.fetch_input(1, &path),
+            Err(ClientError::NoSession)
+        ));
+    }
+}
@@ -1,7 +1,7 @@
-use advent_of_code_2025::{Timer, debug_println, read_file};
+use crate::{AocError, Input, LineError, Solution, Timer, debug, parse_lines, trace};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
+pub enum Direction {
     Left,
     Right,
 }
@@ -29,36 +29,32 @@ impl Lock {
     }
 
     fn turn(&mut self, instruction: &Instruction) {
-        debug_println!(
+        trace!(
             "###############\nLock: {:?}\nInstruction: {:?}",
-            self,
-            instruction
+            self, instruction
         );
         // scale down the amount as appropriate
         let amount_hundreds = instruction.amount / 100;
         let amount = instruction.amount - (amount_hundreds * 100);
         if amount_hundreds != 0 {
-            debug_println!(
+            trace!(
                 "There are {} hundreds in {}.  Increasing zero_passed by {}",
-                amount_hundreds,
-                instruction.amount,
-                amount_hundreds
+                amount_hundreds, instruction.amount, amount_hundreds
             );
             // Increment the 0s passed by this amount
             self.zero_passed += amount_hundreds;
-            debug_println!("Zero passed now: {}", self.zero_passed);
-            debug_println!("Remaining to turn: {}", amount);
+            trace!("Zero passed now: {}", self.zero_passed);
+            trace!("Remaining to turn: {}", amount);
         }
         let mut passed_through_zero: bool = false;
         match instruction.direction {
             Direction::Left => {
-                debug_println!("Turning Left {}", amount);
+                trace!("Turning Left {}", amount);
                 // Have it roll past 0
                 if self.location < amount {
-                    debug_println!(
+                    trace!(
                         "location {} < turn amount {}, so we're rolling past 0. We need to increase location by 100",
-                        self.location,
-                        amount
+                        self.location, amount
                     );
                     if self.location != 0 {
                         passed_through_zero = true;
@@ -66,14 +62,14 @@ impl Lock {
                     self.location += 100;
                 }
                 self.location -= amount;
-                debug_println!("After moving left by {} we're at {}", amount, self.location);
+                trace!("After moving left by {} we're at {}", amount, self.location);
             }
             Direction::Right => {
-                debug_println!("Turning Right {}", amount);
+                trace!("Turning Right {}", amount);
                 self.location += amount;
                 // check if we've rolled past 99
                 if self.location > 99 {
-                    debug_println!(
+                    trace!(
                         "{} > 99, so we decrease by 100 to take us back to the start",
                         self.location
                     );
@@ -82,85 +78,107 @@ impl Lock {
                     }
                     self.location -= 100;
                 }
-                debug_println!(
+                trace!(
                     "After moving right by {} we're at {}",
-                    amount,
-                    self.location
+                    amount, self.location
                 );
             }
         }
         if self.location == 0 {
-            debug_println!("We're at 0, incrementing zero_count");
+            trace!("We're at 0, incrementing zero_count");
             self.zero_count += 1;
             passed_through_zero = true;
         }
         if passed_through_zero {
-            debug_println!("We either ended at, or passed through zero. Incrementing counter");
+            trace!("We either ended at, or passed through zero. Incrementing counter");
             self.zero_passed += 1;
         }
-        debug_println!("After the turn we're {:?}", self);
+        trace!("After the turn we're {:?}", self);
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Instruction {
+pub struct Instruction {
     direction: Direction,
     amount: usize,
 }
 
-fn part_two(instructions: &Vec<Instruction>) {
+fn part_two(instructions: &Vec<Instruction>) -> usize {
     let _timer = Timer::start("Part Two");
-    debug_println!("Creating lock");
+    debug!("Creating lock");
     let mut lock = Lock::default();
     for instruction in instructions {
         lock.turn(instruction);
-        debug_println!("#### Current Status: {:?}", lock);
+        debug!("#### Current Status: {:?}", lock);
     }
-    println!("Part Two Result {}", lock.zero_passed);
+    lock.zero_passed
 }
 
-fn part_one(instructions: &Vec<Instruction>) {
+fn part_one(instructions: &Vec<Instruction>) -> usize {
     let _timer = Timer::start("Part One");
-    debug_println!("Creating lock");
+    debug!("Creating lock");
     let mut lock = Lock::default();
     for instruction in instructions {
         lock.turn(instruction);
-        debug_println!("#### Current Status: {:?}", lock);
+        debug!("#### Current Status: {:?}", lock);
     }
-    println!("Part One Result {}", lock.zero_count);
+    lock.zero_count
 }
 
-fn convert_entry_to_instruction(entry: &str) -> Instruction {
-    let (direction, amount_str) = entry.split_at(1);
-    let amount: usize = amount_str.parse().unwrap();
-    match direction {
-        "L" => Instruction {
-            direction: Direction::Left,
-            amount,
-        },
-        "R" => Instruction {
-            direction: Direction::Right,
-            amount,
-        },
-        _ => panic!("Invalid instruction? {:?}", entry),
-    }
+fn convert_entry_to_instruction(entry: &str) -> Result<Instruction, LineError> {
+    let mut chars = entry.chars();
+    let direction = match chars.next() {
+        Some('L') => Direction::Left,
+        Some('R') => Direction::Right,
+        Some(other) => {
+            return Err(LineError::new(
+                1,
+                format!("Invalid direction {other:?}, expected L or R"),
+            ));
+        }
+        None => return Err(LineError::new(1, "Missing instruction")),
+    };
+    let amount_str = chars.as_str();
+    let amount: usize = amount_str.parse().map_err(|e| {
+        LineError::at(
+            entry,
+            amount_str,
+            format!("Invalid amount {amount_str:?}: {e}"),
+        )
+    })?;
+    Ok(Instruction { direction, amount })
 }
 
-fn parse_input(filename: &str) -> Vec<Instruction> {
-    let _timer = Timer::start(format!("Parsing input {}", filename));
-    read_file(filename)
-        .iter()
-        .map(|entry| convert_entry_to_instruction(entry))
-        .collect()
+fn parse_input(input: &Input) -> Result<Vec<Instruction>, AocError> {
+    let _timer = Timer::start(format!("Parsing input {}", input.path()));
+    parse_lines(
+        input.path(),
+        input.numbered_lines(),
+        convert_entry_to_instruction,
+    )
 }
 
-fn main() {
-    println!("Starting");
-    let _timer = Timer::start("Day 1");
-    let instructions: Vec<Instruction> = parse_input("./data/day1.txt");
-    debug_println!("Instructions: {:?}", instructions);
-    part_one(&instructions);
-    part_two(&instructions);
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<Instruction>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        let instructions = parse_input(input)?;
+        debug!("Instructions: {:?}", instructions);
+        Ok(instructions)
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -182,7 +200,7 @@ mod tests {
         #[case] instruction: Instruction,
         #[case] final_location: usize,
     ) {
-        let mut lock = lock.clone();
+        let mut lock = lock;
         lock.turn(&instruction);
         assert_eq!(lock.location, final_location);
     }
@@ -241,7 +259,7 @@ mod tests {
 
     #[rstest]
     fn test_from_test_data() {
-        let input = parse_input("./data/day1_test");
+        let input = parse_input(&Input::read("./data/day1_test").unwrap()).unwrap();
         let mut lock = Lock::default();
         for instruction in input {
             lock.turn(&instruction);
@@ -284,16 +302,21 @@ mod tests {
     #[case("L10", Instruction {direction: Direction::Left, amount: 10})]
     #[case("R10", Instruction {direction: Direction::Right, amount: 10})]
     fn test_convert_entry_to_instruction(#[case] input: &str, #[case] want: Instruction) {
-        assert_eq!(convert_entry_to_instruction(input), want);
+        assert_eq!(convert_entry_to_instruction(input), Ok(want));
     }
 
     #[rstest]
-    #[case("l10")]
-    #[case("nonsense")]
-    #[case("10l")]
-    #[case("P10")]
-    #[should_panic]
-    fn test_convert_entry_to_instruction_panics(#[case] input: &str) {
-        convert_entry_to_instruction(input);
+    #[case("l10", 1)]
+    #[case("nonsense", 1)]
+    #[case("10l", 1)]
+    #[case("P10", 1)]
+    #[case("", 1)]
+    #[case("L", 2)]
+    #[case("R1x", 2)]
+    fn test_convert_entry_to_instruction_errors(#[case] input: &str, #[case] column: usize) {
+        assert_eq!(
+            convert_entry_to_instruction(input).unwrap_err().column,
+            column
+        );
     }
 }
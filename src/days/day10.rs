@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Machine {
+    indicator_lights: Vec<bool>,
+    wiring_schematics: Vec<Vec<usize>>,
+    joltage_requirements: Vec<usize>,
+}
+
+impl Machine {
+    fn new(
+        indicator_lights: Vec<bool>,
+        wiring_schematics: Vec<Vec<usize>>,
+        joltage_requirements: Vec<usize>,
+    ) -> Self {
+        Self {
+            indicator_lights,
+            wiring_schematics,
+            joltage_requirements,
+        }
+    }
+}
+
+fn parse_machine(f: &str) -> Result<Machine, LineError> {
+    let mut line = f.split(' ');
+    // First line is indicators.
+    // split always produces at least one item, even for an empty line
+    let indicators = line.next().unwrap();
+    let indicators_column = column_of(f, indicators);
+    let indicator_lights: Vec<bool> = indicators
+        .chars()
+        .enumerate()
+        .filter_map(|(i, c)| match c {
+            '[' | ']' => None,
+            '.' => Some(Ok(false)),
+            '#' => Some(Ok(true)),
+            _ => Some(Err(LineError::new(
+                indicators_column + i,
+                format!("What is: {c}?"),
+            ))),
+        })
+        .collect::<Result<_, _>>()?;
+    let mut wiring_schematics = vec![];
+    let mut buttons = vec![];
+    let mut joltage_requirements = vec![];
+    for component in line {
+        debug!("{}", component);
+        if component.starts_with('(') {
+            wiring_schematics.push(parse_bracketed(f, component, ['(', ')'], ",")?);
+            buttons.push(component);
+        } else {
+            // assuming joltage, should be safe given what I see in the input.
+            // There's only one of these entries
+            joltage_requirements = parse_bracketed(f, component, ['{', '}'], ",")?;
+        }
+    }
+    // Every button has to be wired to a light and a joltage counter that exist
+    for (button, wiring) in buttons.iter().zip(&wiring_schematics) {
+        let items = button
+            .trim()
+            .trim_matches(['(', ')'])
+            .split(',')
+            .filter(|item| !item.trim().is_empty());
+        for (item, &index) in items.zip(wiring) {
+            let reason = if index >= indicator_lights.len() {
+                format!(
+                    "Button wired to light {index}, but there are only {} lights",
+                    indicator_lights.len()
+                )
+            } else if index >= joltage_requirements.len() {
+                format!(
+                    "Button wired to counter {index}, but there are only {} joltage counters",
+                    joltage_requirements.len()
+                )
+            } else {
+                continue;
+            };
+            return Err(LineError::at(f, item.trim(), reason));
+        }
+    }
+    Ok(Machine::new(
+        indicator_lights,
+        wiring_schematics,
+        joltage_requirements,
+    ))
+}
+
+fn parse_input(input: &Input) -> Result<Vec<Machine>, AocError> {
+    let filename = input.path();
+    let _t = Timer::start(format!("Parsing file {filename}"));
+    parse_lines(filename, input.numbered_lines(), parse_machine)
+}
+
+// Pressing a button twice leaves every counter's parity unchanged.  So any solution is some set of
+// buttons pressed once (which has to fix the parity of every counter), plus a solution to the
+// remaining, now all even, requirements pressed twice over.  That second part is the same problem
+// again with every requirement halved.
+fn fewest_joltage_presses(
+    requirements: &[usize],
+    single_presses: &[(Vec<usize>, usize)],
+    cache: &mut HashMap<Vec<usize>, Option<usize>>,
+) -> Option<usize> {
+    if requirements.iter().all(|r| *r == 0) {
+        return Some(0);
+    }
+    if let Some(known) = cache.get(requirements) {
+        return *known;
+    }
+    let mut best: Option<usize> = None;
+    for (effect, presses) in single_presses {
+        let fits = effect
+            .iter()
+            .zip(requirements)
+            .all(|(e, r)| e <= r && (r - e) % 2 == 0);
+        if !fits {
+            continue;
+        }
+        let halved: Vec<usize> = effect
+            .iter()
+            .zip(requirements)
+            .map(|(e, r)| (r - e) / 2)
+            .collect();
+        if let Some(rest) = fewest_joltage_presses(&halved, single_presses, cache) {
+            let total = presses + 2 * rest;
+            best = Some(best.map_or(total, |b| b.min(total)));
+        }
+    }
+    cache.insert(requirements.to_vec(), best);
+    best
+}
+
+/// The fewest button presses that bring every counter up to its requirement, if any can
+fn machine_joltage_presses(machine: &Machine) -> Option<usize> {
+    // Effect on the counters of pressing each subset of the buttons exactly once
+    let single_presses: Vec<(Vec<usize>, usize)> = (0..1usize << machine.wiring_schematics.len())
+        .map(|mask| {
+            let mut effect = vec![0; machine.joltage_requirements.len()];
+            for (i, button) in machine.wiring_schematics.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    for toggle in button {
+                        effect[*toggle] += 1;
+                    }
+                }
+            }
+            (effect, mask.count_ones() as usize)
+        })
+        .collect();
+    let mut cache = HashMap::new();
+    fewest_joltage_presses(&machine.joltage_requirements, &single_presses, &mut cache)
+}
+
+/// `None` if some machine can't reach its joltage requirements
+fn part_two(source: &[Machine]) -> Option<usize> {
+    let _t = Timer::start("Part Two");
+    source
+        .iter()
+        .map(|f| {
+            debug!("{:?}", f);
+            let presses = machine_joltage_presses(f);
+            if presses.is_none() {
+                warn!("No way to reach the joltage requirements of {f:?}");
+            }
+            presses
+        })
+        .sum()
+}
+
+/// The fewest button presses that light up the machine's pattern, if it takes 10 or fewer
+fn machine_light_presses(machine: &Machine) -> Option<usize> {
+    // Assumption: No machine will take more than 10 button presses (gut feeling is that'd be an impractical number of combinations to check)
+    for n in 1..11 {
+        for sequence in machine
+            .wiring_schematics
+            .iter()
+            .combinations_with_replacement(n)
+        {
+            let mut state = vec![false; machine.indicator_lights.len()];
+            for button in sequence {
+                for toggle in button {
+                    state[*toggle] = !state[*toggle];
+                }
+            }
+            if state == machine.indicator_lights {
+                return Some(n);
+            }
+        }
+    }
+    None
+}
+
+/// `None` if some machine's lights can't be matched
+fn part_one(source: &[Machine]) -> Option<usize> {
+    let _t = Timer::start("Part One");
+    // could parallelise this with a little bit of effort.
+    source
+        .iter()
+        .map(|f| {
+            let presses = machine_light_presses(f);
+            if presses.is_none() {
+                warn!("No way to light up {f:?}");
+            }
+            presses
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Machine>;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<usize> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_read_file() {
+        let data = parse_input(&Input::read("./data/day10_test").unwrap()).unwrap();
+        assert_eq!(
+            data,
+            [
+                Machine::new(
+                    vec![false, true, true, false],
+                    vec![
+                        vec![3],
+                        vec![1, 3],
+                        vec![2],
+                        vec![2, 3],
+                        vec![0, 2],
+                        vec![0, 1]
+                    ],
+                    vec![3, 5, 4, 7]
+                ),
+                Machine::new(
+                    vec![false, false, false, true, false],
+                    vec![
+                        vec![0, 2, 3, 4],
+                        vec![2, 3],
+                        vec![0, 4],
+                        vec![0, 1, 2],
+                        vec![1, 2, 3, 4],
+                    ],
+                    vec![7, 5, 12, 7, 2]
+                ),
+                Machine::new(
+                    vec![false, true, true, true, false, true],
+                    vec![
+                        vec![0, 1, 2, 3, 4],
+                        vec![0, 3, 4],
+                        vec![0, 1, 2, 4, 5],
+                        vec![1, 2],
+                    ],
+                    vec![10, 11, 11, 5, 10, 5]
+                )
+            ]
+        )
+    }
+
+    #[rstest]
+    fn test_part_one_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day10_test").unwrap()).unwrap();
+        assert_eq!(part_one(&source), Some(7));
+    }
+
+    #[rstest]
+    fn test_part_two_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day10_test").unwrap()).unwrap();
+        assert_eq!(part_two(&source), Some(33));
+    }
+
+    #[rstest]
+    #[case(0, 10)]
+    #[case(1, 12)]
+    #[case(2, 11)]
+    fn test_sample_joltage_presses(#[case] machine: usize, #[case] want: usize) {
+        let source = parse_input(&Input::read("./data/day10_test").unwrap()).unwrap();
+        assert_eq!(machine_joltage_presses(&source[machine]), Some(want));
+    }
+
+    #[rstest]
+    #[case("[.] (0) {0}", Some(0))] // Nothing to do
+    #[case("[.] (0) {5}", Some(5))] // One button, pressed over and over
+    #[case("[..] (0,1) (0) {3,1}", Some(3))] // Odd counts need a single press first
+    #[case("[..] (0,1) {2,1}", None)] // The counters can only go up together
+    #[case("[..] (0) {1,1}", None)] // Nothing reaches the second counter
+    fn test_joltage_presses(#[case] line: &str, #[case] want: Option<usize>) {
+        assert_eq!(machine_joltage_presses(&parse_machine(line).unwrap()), want);
+    }
+
+    #[rstest]
+    fn test_no_answer() {
+        // Button 0 only ever lights both lights together
+        let machines = [parse_machine("[#.] (0,1) {1,2}").unwrap()];
+        assert_eq!(part_one(&machines), None);
+        assert_eq!(part_two(&machines), None);
+    }
+
+    #[rstest]
+    #[case("[.x#] (1) {2}", LineError::new(3, "What is: x?"))]
+    #[case(
+        "[.#] (1,y) {2}",
+        LineError::new(9, "Invalid number \"y\": invalid digit found in string")
+    )]
+    #[case(
+        "[.#] (0) (1,2) {2,3}",
+        LineError::new(13, "Button wired to light 2, but there are only 2 lights")
+    )]
+    #[case(
+        "[.##] (0,2) {2,3}",
+        LineError::new(10, "Button wired to counter 2, but there are only 2 joltage counters")
+    )]
+    fn test_parse_machine_errors(#[case] line: &str, #[case] want: LineError) {
+        assert_eq!(parse_machine(line), Err(want));
+    }
+}
@@ -0,0 +1,148 @@
+use itertools::Itertools;
+
+use crate::*;
+
+fn parse_input(input: &Input) -> Result<Graph<String>, AocError> {
+    let filename = input.path();
+    let _t = Timer::start(format!("Parsing file {filename}"));
+    let mut world = Graph::new();
+    for (line_no, line) in input.numbered_lines() {
+        let (key, outputs) =
+            parse_key_value(line, ":").map_err(|e| e.in_file(filename, line_no, line))?;
+        world.add_node(key.to_string());
+        for output in outputs.split_whitespace() {
+            world.add_edge(key.to_string(), output.to_string());
+        }
+    }
+    Ok(world)
+}
+
+/// How many routes lead from `from` to `to` passing through every device in `via`, in any order.
+/// `None` if a loop on the way makes that infinitely many.
+fn count_paths(world: &Graph<String>, from: &str, to: &str, via: &[&str]) -> Option<usize> {
+    let (Some(from), Some(to)) = (world.id(from), world.id(to)) else {
+        return Some(0);
+    };
+    let Some(via) = via
+        .iter()
+        .map(|name| world.id(*name))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Some(0);
+    };
+    // A route through every stop visits them in some order, so split it at the stops and
+    // multiply the choices for each leg.  Only one order can have any routes, as the devices
+    // can't loop, but trying them all is cheap for a couple of stops.
+    via.iter()
+        .permutations(via.len())
+        .map(|order| {
+            let stops: Vec<usize> = std::iter::once(from)
+                .chain(order.into_iter().copied())
+                .chain([to])
+                .collect();
+            stops
+                .windows(2)
+                .map(|leg| (leg[0], leg[1], world.count_paths(leg[0], leg[1])))
+                .collect::<Vec<_>>()
+        })
+        .map(|legs| {
+            // A loop on one leg is harmless if another leg can't be done at all
+            if legs.iter().any(|&(_, _, count)| count == Some(0)) {
+                return Some(0);
+            }
+            legs.into_iter()
+                .map(|(start, end, count)| {
+                    if count.is_none()
+                        && let Some(looped) = world.find_loop_between(start, end)
+                    {
+                        let names = looped.iter().map(|&id| world.name(id)).sorted().join(", ");
+                        warn!(
+                            "Devices between {} and {} loop back on themselves: {names}",
+                            world.name(start),
+                            world.name(end)
+                        );
+                    }
+                    count
+                })
+                .product()
+        })
+        .sum()
+}
+
+fn part_two(world: &Graph<String>) -> Option<usize> {
+    let _t = Timer::start("Part Two");
+    count_paths(world, "svr", "out", &["dac", "fft"])
+}
+
+fn part_one(world: &Graph<String>) -> Option<usize> {
+    let _t = Timer::start("Part One");
+    count_paths(world, "you", "out", &[])
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Graph<String>;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<usize> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day11_test").unwrap()).unwrap();
+        assert_eq!(part_one(&source), Some(5));
+    }
+
+    #[rstest]
+    fn test_part_two_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day11_2_test").unwrap()).unwrap();
+        assert_eq!(part_two(&source), Some(2));
+    }
+
+    #[rstest]
+    #[case("you: aaa\naaa: bbb out\nbbb: aaa\n", part_one)]
+    #[case("svr: aaa\naaa: aaa dac\ndac: fft\nfft: out\n", part_two)] // On the leg to dac
+    fn test_loop_has_no_answer(
+        #[case] text: &str,
+        #[case] part: fn(&Graph<String>) -> Option<usize>,
+    ) {
+        let source = parse_input(&Input::from_string("test", text)).unwrap();
+        assert_eq!(part(&source), None);
+    }
+
+    #[rstest]
+    #[case("you: aaa out\naaa: bbb\nbbb: aaa\n", 1)] // Never gets to out
+    #[case("ccc: ddd\nddd: ccc out\nyou: out\n", 1)] // Can't be reached from you
+    #[case("you: aaa\naaa: out bbb\nbbb: ccc\nccc: bbb\n", 1)]
+    fn test_loop_off_the_route_is_allowed(#[case] text: &str, #[case] want: usize) {
+        let source = parse_input(&Input::from_string("test", text)).unwrap();
+        assert_eq!(part_one(&source), Some(want));
+        assert_eq!(part_two(&source), Some(0));
+    }
+
+    #[rstest]
+    fn test_loop_off_a_leg_is_allowed() {
+        // dac can't reach out, so the loop between svr and dac doesn't matter
+        let text = "svr: aaa fft\naaa: bbb\nbbb: aaa dac\nfft: out\n";
+        let source = parse_input(&Input::from_string("test", text)).unwrap();
+        assert_eq!(part_two(&source), Some(0));
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::*;
+
+// Made it all the way to day12 before I broke out a regex, and LazyLock.
+static REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?<p_index>^\d+):$|(?<dimensions>^\d+x\d+:)(?<requirements>.+)").unwrap()
+});
+
+#[derive(Debug, PartialEq)]
+pub struct Present {
+    index: usize,
+    /// Every distinct way the present can be placed, turned or flipped
+    shapes: Vec<Polyomino>,
+}
+
+impl Present {
+    fn new(index: usize, shape: &Polyomino) -> Self {
+        Self {
+            index,
+            shapes: shape.orientations(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Region {
+    height: usize,
+    width: usize,
+    requirements: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    presents: Vec<Present>,
+    regions: Vec<Region>,
+}
+
+// Written back out the way the puzzle lists it, with each present turned to its canonical
+// orientation
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for present in &self.presents {
+            writeln!(f, "{}:\n{}", present.index, present.shapes[0])?;
+        }
+        for region in &self.regions {
+            writeln!(
+                f,
+                "{}x{}: {}",
+                region.height,
+                region.width,
+                region.requirements.iter().join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `line` is a row of a present's shape, leaving `Polyomino::parse` to complain about
+/// anything odd further along it
+fn is_shape_row(line: &str) -> bool {
+    line.starts_with(['#', '.'])
+}
+
+fn parse_input(input: &Input) -> Result<Problem, AocError> {
+    let filename = input.path();
+    let mut presents = vec![];
+    let mut regions = vec![];
+    // Each present is a block of its own, and the regions are all listed in the last block
+    for block in input.paragraphs() {
+        let mut source = block.numbered_lines().peekable();
+        while let Some((line_no, line)) = source.next() {
+            let in_file = |e: LineError| e.in_file(filename, line_no, line);
+            if let Some(caps) = REGEX.captures(line) {
+                debug!("{:?}", caps);
+                if let Some(p_index) = &caps.name("p_index") {
+                    let p_index = p_index.as_str().trim_matches(':');
+                    // The present's shape runs until the block ends or something else starts
+                    let shape = Polyomino::parse(
+                        filename,
+                        source.peeking_take_while(|(_, line)| is_shape_row(line)),
+                    )?;
+                    if shape.is_empty() {
+                        return Err(in_file(LineError::new(
+                            line.len() + 1,
+                            "Expected the present's shape on the following lines",
+                        )));
+                    }
+                    let new_present: Present = Present::new(
+                        p_index.parse().map_err(|e| {
+                            in_file(LineError::at(
+                                line,
+                                p_index,
+                                format!("Unable to convert present index to usize: {e}"),
+                            ))
+                        })?,
+                        &shape,
+                    );
+                    presents.push(new_present);
+                } else if let Some(region) = &caps.name("dimensions") {
+                    let dimensions: Vec<usize> = region
+                        .as_str()
+                        .trim_matches(':')
+                        .split('x')
+                        .map(|x| {
+                            x.parse::<usize>().map_err(|e| {
+                                in_file(LineError::at(
+                                    line,
+                                    x,
+                                    format!("Unable to convert a dimension to usize: {e}"),
+                                ))
+                            })
+                        })
+                        .collect::<Result<_, _>>()?;
+                    debug!("{:?}", dimensions);
+                    let requirements: Vec<usize> = caps
+                        .name("requirements")
+                        .expect("Somehow got dimensions without requirements?")
+                        .as_str()
+                        .trim_start_matches(' ')
+                        .split_whitespace()
+                        .map(|f| {
+                            f.parse::<usize>().map_err(|e| {
+                                in_file(LineError::at(
+                                    line,
+                                    f,
+                                    format!("Unable to convert a present count to usize: {e}"),
+                                ))
+                            })
+                        })
+                        .collect::<Result<_, _>>()?;
+                    debug!("Requirements: {requirements:?}");
+                    regions.push(Region {
+                        height: dimensions[0],
+                        width: dimensions[1],
+                        requirements,
+                    });
+                }
+            } else {
+                return Err(in_file(LineError::new(1, "Couldn't parse line")));
+            };
+        }
+    }
+
+    Ok(Problem { presents, regions })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Problem;
+    type PartOne = Unsolved;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        let _t = Timer::start(format!("Parsing file {}", input.path()));
+        let data = parse_input(input)?;
+        debug!("{data}");
+        Ok(data)
+    }
+
+    fn part_one(_input: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_two(_input: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_present() {
+        let input = Input::from_string("test", "##.\n#..\n##.\n");
+        let shape = Polyomino::parse(input.path(), input.numbered_lines()).unwrap();
+
+        let get = Present::new(0, &shape);
+        assert_eq!(get.index, 0);
+        // The C shape is symmetrical top to bottom, so it only has four distinct orientations
+        let masks: Vec<Vec<u64>> = get.shapes.iter().map(Polyomino::row_masks).collect();
+        assert_eq!(
+            masks,
+            [
+                vec![0b111, 0b101],
+                vec![0b11, 0b10, 0b11],
+                vec![0b11, 0b01, 0b11],
+                vec![0b101, 0b111]
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_parse_presents() {
+        let problem = parse_input(&Input::read("./data/day12_test").unwrap()).unwrap();
+        let orientations: Vec<usize> = problem.presents.iter().map(|p| p.shapes.len()).collect();
+        assert_eq!(orientations, [8, 8, 2, 4, 4, 2]);
+        assert_eq!(problem.regions.len(), 3);
+    }
+
+    #[rstest]
+    fn test_region_after_shape() {
+        // No blank line between the last present and the regions
+        let input = Input::from_string("test", "0:\n##\n#.\n2x3: 1\n3x3: 2\n");
+        let problem = parse_input(&input).unwrap();
+        assert_eq!(problem.presents.len(), 1);
+        assert_eq!(problem.presents[0].shapes[0].len(), 3);
+        assert_eq!(problem.to_string(), "0:\n##\n#.\n\n2x3: 1\n3x3: 2\n");
+    }
+
+    #[rstest]
+    fn test_bad_shape_character() {
+        let input = Input::from_string("test", "0:\n##\n#x\n\n2x3: 1\n");
+        match parse_input(&input) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (3, 2)),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+}
@@ -1,25 +1,14 @@
 use std::ops::RangeInclusive;
 
-use advent_of_code_2025::*;
+use crate::*;
 
-fn parse_input(filename: &str) -> Vec<RangeInclusive<usize>> {
+fn parse_input(input: &Input) -> Result<Vec<RangeInclusive<usize>>, AocError> {
     let _timer = Timer::start("Parsing input".to_owned());
-    debug_println!("Reading {}", filename);
-    let source = read_file(filename);
-    let line = source.first().unwrap(); // Input only has a single line
-    debug_println!("Converting line into a vec of inclusive ranges");
+    debug!("Reading {}", input.path());
+    let line = input.first_line()?; // Input only has a single line
+    debug!("Converting line into a vec of inclusive ranges");
     line.split(",") // entries are separated by commas
-        .map(|x| {
-            x.split("-") // each entry takes the form "start_number dash end_number", so we need to parse those digits.
-                .map(|digit| digit.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>()
-        })
-        .map(|entry| {
-            // Then we take those pairings of digits and turn them into std:ops::Ranges.
-            let start: usize = entry[0];
-            let end: usize = entry[1];
-            start..=end
-        })
+        .map(|entry| parse_range(line, entry).map_err(|e| e.in_file(input.path(), 1, line)))
         .collect()
 }
 
@@ -33,7 +22,7 @@ fn remove_first_and_last_letters(id: &str) -> String {
 }
 
 fn invalid_id_part_two(id: usize) -> bool {
-    debug_println!("Evaluating {id} using part two approach");
+    debug!("Evaluating {id} using part two approach");
     // Use the part one approach first
     if invalid_id_part_one(id) {
         return true;
@@ -42,9 +31,9 @@ fn invalid_id_part_two(id: usize) -> bool {
     // This is taking advantage of the "string doubling" technique I found searching for more optimal approaches than brute forcing it
     // Essentially, if a string S is made of repeating patterns, then the string will exist inside S+S that has first and last letters trimmed from it
     let doubled_id: String = format!("{id}{id}");
-    debug_println!("Doubled string: {}", doubled_id);
+    debug!("Doubled string: {}", doubled_id);
     let stripped = remove_first_and_last_letters(&doubled_id);
-    debug_println!("After stripping first and last: {stripped}");
+    debug!("After stripping first and last: {stripped}");
     if stripped.contains(&format!("{id}")) {
         return true;
     }
@@ -52,12 +41,12 @@ fn invalid_id_part_two(id: usize) -> bool {
 }
 
 fn invalid_id_part_one(id: usize) -> bool {
-    debug_println!("Evaluating {id} using part one approach");
+    debug!("Evaluating {id} using part one approach");
     let text = format!("{id}");
     let (first, second) = text.split_at(text.len() / 2);
-    debug_println!("First: {first}. Second: {second}");
+    debug!("First: {first}. Second: {second}");
     if !second.starts_with("0") && first == second {
-        debug_println!("Invalid ID found: {first} == {second}");
+        debug!("Invalid ID found: {first} == {second}");
         return true;
     }
     false
@@ -67,15 +56,14 @@ fn part_one(data: &Vec<RangeInclusive<usize>>) -> usize {
     let _timer = Timer::start("Part One".to_owned());
     let mut tally = 0;
     for range in data {
-        debug_println!("Evaluating range\t{:?}", range);
+        debug!("Evaluating range\t{:?}", range);
         for id in range.clone() {
             if invalid_id_part_one(id) {
-                debug_println!("Invalid ID found: {id}");
+                debug!("Invalid ID found: {id}");
                 tally += id;
             }
         }
     }
-    println!("Part One Result: {tally}");
     tally
 }
 
@@ -83,26 +71,39 @@ fn part_two(data: &Vec<RangeInclusive<usize>>) -> usize {
     let _timer = Timer::start("Part Two".to_owned());
     let mut tally = 0;
     for range in data {
-        debug_println!("Evaluating range\t{:?}", range);
+        debug!("Evaluating range\t{:?}", range);
         for id in range.clone() {
             if invalid_id_part_two(id) {
-                debug_println!("Invalid ID found: {id}");
+                debug!("Invalid ID found: {id}");
                 tally += id;
             }
         }
     }
 
-    println!("Part Two Result: {tally}");
-
     tally
 }
 
-fn main() {
-    let _timer = Timer::start("Day Two".to_owned());
-    let data = parse_input("./data/day2.txt");
-    debug_println!("{:?}", data);
-    part_one(&data);
-    part_two(&data);
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<RangeInclusive<usize>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        let data = parse_input(input)?;
+        debug!("{:?}", data);
+        Ok(data)
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -112,13 +113,13 @@ mod tests {
 
     #[rstest]
     fn test_sample_data_part_one() {
-        let data = parse_input("./data/day2_test");
+        let data = parse_input(&Input::read("./data/day2_test").unwrap()).unwrap();
         assert_eq!(part_one(&data), 1227775554);
     }
 
     #[rstest]
     fn test_sample_data_part_two() {
-        let data = parse_input("./data/day2_test");
+        let data = parse_input(&Input::read("./data/day2_test").unwrap()).unwrap();
         assert_eq!(part_two(&data), 4174379265);
     }
 
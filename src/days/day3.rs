@@ -0,0 +1,169 @@
+use std::char;
+
+use crate::*;
+
+// How many batteries each part turns on in every bank
+const PART_ONE_BATTERIES: usize = 2;
+const PART_TWO_BATTERIES: usize = 12;
+
+fn parse_input(input: &Input) -> Result<Vec<String>, AocError> {
+    let _t = Timer::start(format!("Parsing file: {}", input.path()));
+    input
+        .numbered_lines()
+        .map(
+            |(line_no, bank)| match bank.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+                Some((column, c)) => Err(AocError::parse(
+                    input.path(),
+                    line_no,
+                    column + 1,
+                    bank,
+                    format!("Battery joltage must be a digit, not {c:?}"),
+                )),
+                None if bank.len() < PART_TWO_BATTERIES => Err(AocError::parse(
+                    input.path(),
+                    line_no,
+                    1,
+                    bank,
+                    format!(
+                        "A bank needs at least {PART_TWO_BATTERIES} batteries, not {}",
+                        bank.len()
+                    ),
+                )),
+                None => Ok(bank.to_owned()),
+            },
+        )
+        .collect()
+}
+
+fn evaluate_bank(bank: &str, target_length: usize) -> usize {
+    let letters_char: Vec<char> = bank.chars().collect();
+    debug!("Bank: {letters_char:?}");
+    let letters: Vec<u32> = bank.chars().map(|f| f.to_digit(10).unwrap()).collect();
+    let mut chosen_indexes: Vec<usize> = vec![];
+    let mut start_index: usize = 0;
+    let mut answer = String::new();
+    loop {
+        if chosen_indexes.len() >= target_length {
+            break;
+        }
+        // Define the range to evaluate
+        if !chosen_indexes.is_empty() {
+            // unwrap is safe here because previously we handled the empty case.
+            start_index = chosen_indexes.last().copied().unwrap() + 1;
+        }
+        let end_index = letters.len() - target_length + chosen_indexes.len();
+        let range = start_index..=end_index;
+        debug!("Evaluating range {:?}", range);
+        // evaluate the range
+        let max_index = &letters[range]
+            .iter()
+            .enumerate()
+            // This is annoying.  max_by_key returns last matching index, min returns first.
+            // Stdlib's Reverse enables biggest to seem like smallest.
+            .min_by_key(|&(_idx, &val)| std::cmp::Reverse(val)) // find the "largest" number
+            .map(|(idx, _val)| idx + start_index) // drop the value and return the index + offset.
+            .unwrap(); // Shouldn't be possible to panic.
+        debug!(
+            "Chosen index: {:?}, which has value {:?}",
+            *max_index, letters_char[*max_index]
+        );
+        chosen_indexes.push(*max_index);
+        answer += &letters_char[*max_index].to_string();
+        debug!("Answer so far: {answer}");
+    }
+    // Convert the answer back to a number
+    answer.parse().unwrap()
+}
+
+fn part_one(banks: &[String]) -> usize {
+    let _t = Timer::start("Part One");
+    let answer: usize = banks
+        .iter()
+        .map(|f| evaluate_bank(f, PART_ONE_BATTERIES))
+        .sum();
+    answer
+}
+
+fn part_two(banks: &[String]) -> usize {
+    let _t = Timer::start("Part Two");
+    let answer: usize = banks
+        .iter()
+        .map(|f| evaluate_bank(f, PART_TWO_BATTERIES))
+        .sum();
+    answer
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        let banks = parse_input(input)?;
+        debug!("{:?}", banks);
+        Ok(banks)
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("987654321111111", 98)]
+    #[case("811111111111119", 89)]
+    #[case("234234234234278", 78)]
+    #[case("818181911112111", 92)]
+    fn test_known_bank_part_one(#[case] input: &str, #[case] want: usize) {
+        assert_eq!(evaluate_bank(input, 2), want);
+    }
+
+    #[rstest]
+    #[case("987654321111111", 987654321111)]
+    #[case("811111111111119", 811111111119)]
+    #[case("234234234234278", 434234234278)]
+    #[case("818181911112111", 888911112111)]
+    fn test_known_bank_part_two(#[case] input: &str, #[case] want: usize) {
+        assert_eq!(evaluate_bank(input, 12), want);
+    }
+
+    #[rstest]
+    fn test_part_one_using_test_data() {
+        let banks = parse_input(&Input::read("./data/day3_test").unwrap()).unwrap();
+        assert_eq!(part_one(&banks), 357);
+    }
+
+    #[rstest]
+    fn test_part_two_using_test_data() {
+        let banks = parse_input(&Input::read("./data/day3_test").unwrap()).unwrap();
+        assert_eq!(part_two(&banks), 3121910778619);
+    }
+
+    #[rstest]
+    #[case("98765432111\n", 11)]
+    #[case("987654321111\n\n", 0)]
+    fn test_short_bank_is_rejected(#[case] text: &str, #[case] length: usize) {
+        match parse_input(&Input::from_string("test", text)) {
+            Err(AocError::Parse { column, reason, .. }) => {
+                assert_eq!(column, 1);
+                assert_eq!(
+                    reason,
+                    format!("A bank needs at least 12 batteries, not {length}")
+                );
+            }
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+fn evaluate_map(map: &Grid<char>) -> (usize, Grid<char>) {
+    let mut new_map = map.clone();
+    let mut can_be_moved = 0;
+    for ((x, y), &cell) in map.iter() {
+        trace!("{x},{y}");
+        if cell == '@' {
+            let count = map
+                .neighbours8(x, y)
+                .filter(|&position| map[position] == '@')
+                .count();
+            if count < 4 {
+                debug!("{x},{y} is safe to move. Updating map and incrementing counter");
+                new_map[(x, y)] = '.';
+                can_be_moved += 1;
+            }
+        }
+    }
+    (can_be_moved, new_map)
+}
+
+fn part_two(data: &Grid<char>) -> usize {
+    let _t = Timer::start("Part Two");
+    let mut final_count = 0;
+    let mut map = data.clone();
+    loop {
+        let (can_be_moved, new_map) = evaluate_map(&map);
+        map = new_map;
+        if can_be_moved == 0 {
+            break;
+        } else {
+            final_count += can_be_moved;
+        }
+    }
+    final_count
+}
+
+fn part_one(data: &Grid<char>) -> usize {
+    let _t = Timer::start("Part One");
+    let (can_be_moved, _new_map) = evaluate_map(data);
+    can_be_moved
+}
+
+fn parse_input(input: &Input) -> Result<Grid<char>, AocError> {
+    let _t = Timer::start(format!("Parsing {}", input.path()));
+    Grid::parse(input, |c| matches!(c, '@' | '.').then_some(c))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one_test_input() {
+        assert_eq!(
+            part_one(&parse_input(&Input::read("./data/day4_test").unwrap()).unwrap()),
+            13
+        );
+    }
+
+    #[rstest]
+    fn test_part_two_test_input() {
+        assert_eq!(
+            part_two(&parse_input(&Input::read("./data/day4_test").unwrap()).unwrap()),
+            43
+        );
+    }
+}
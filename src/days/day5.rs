@@ -0,0 +1,74 @@
+use crate::*;
+
+fn parse_file(input: &Input) -> Result<(RangeSet<usize>, Vec<usize>), AocError> {
+    let _t = Timer::start(format!("Parsing file: {}", input.path()));
+    // The fresh ranges come first, then a blank line, then the ingredients
+    let [ranges_block, ingredients_block] = parse_blocks(input, ["fresh ranges", "ingredients"])?;
+    let mut fresh_ranges = RangeSet::new();
+    for range in parse_lines(input.path(), ranges_block.numbered_lines(), |line| {
+        parse_range(line, line)
+    })? {
+        debug!("Adding inclusive range {range:?}");
+        fresh_ranges.insert(range);
+    }
+    let ingredients = parse_lines(input.path(), ingredients_block.numbered_lines(), |line| {
+        debug!("Got ingredient id: {line}");
+        parse_number(line, line)
+    })?;
+    Ok((fresh_ranges, ingredients))
+}
+
+fn part_two(fresh_ranges: &RangeSet<usize>) -> u128 {
+    let _t = Timer::start("Part Two");
+    // Overlapping ranges were merged as they were parsed, so this doesn't count any id twice
+    fresh_ranges.len()
+}
+
+fn part_one(fresh_ranges: &RangeSet<usize>, ingredients: &[usize]) -> usize {
+    let _t = Timer::start("Part One");
+    ingredients
+        .iter()
+        .filter(|&&ingredient| fresh_ranges.contains(ingredient))
+        .count()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (RangeSet<usize>, Vec<usize>);
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        parse_file(input)
+    }
+
+    fn part_one((fresh_ranges, ingredients): &Self::Input) -> usize {
+        part_one(fresh_ranges, ingredients)
+    }
+
+    fn part_two((fresh_ranges, _ingredients): &Self::Input) -> u128 {
+        part_two(fresh_ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one() {
+        let (fresh_ranges, ingredients) =
+            parse_file(&Input::read("./data/day5_test").unwrap()).unwrap();
+        assert_eq!(part_one(&fresh_ranges, &ingredients), 3);
+    }
+
+    #[rstest]
+    fn test_part_two() {
+        let (fresh_ranges, _ingredients) =
+            parse_file(&Input::read("./data/day5_test").unwrap()).unwrap();
+        assert_eq!(part_two(&fresh_ranges), 14);
+    }
+}
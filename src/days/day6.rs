@@ -1,7 +1,7 @@
-use advent_of_code_2025::*;
+use crate::*;
 
 #[derive(Debug, Clone, Copy)]
-enum Operator {
+pub enum Operator {
     Add,
     Subtract,
     Multiply,
@@ -9,7 +9,7 @@ enum Operator {
 }
 
 #[derive(Debug, Clone)]
-struct Calculation {
+pub struct Calculation {
     operator: Operator,
     values: Vec<isize>,
 }
@@ -19,50 +19,47 @@ fn split_line(line: &str) -> Vec<&str> {
     line.split(' ').filter(|x| !x.is_empty()).collect()
 }
 
-fn parse_operators(line: &str) -> Vec<Operator> {
+fn parse_operators(line: &str) -> Result<Vec<Operator>, LineError> {
     split_line(line)
         .into_iter()
         .map(|x| match x {
-            "+" => Operator::Add,
-            "-" => Operator::Subtract,
-            "*" => Operator::Multiply,
-            "/" => Operator::Divide,
-            _ => panic!("Unknown operator: {x}"),
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Subtract),
+            "*" => Ok(Operator::Multiply),
+            "/" => Ok(Operator::Divide),
+            _ => Err(LineError::at(line, x, format!("Unknown operator: {x}"))),
         })
         .collect()
 }
 
-fn parse_input_part_two(source: &[String]) -> Vec<Calculation> {
+// The operators are on the last line of the worksheet, with the numbers above them
+fn split_worksheet(input: &Input) -> Result<(Vec<&str>, Vec<Operator>), AocError> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let Some(line) = lines.pop() else {
+        return Err(AocError::parse(input.path(), 1, 1, "", "Input is empty"));
+    };
+    let operators =
+        parse_operators(line).map_err(|e| e.in_file(input.path(), lines.len() + 1, line))?;
+    Ok((lines, operators))
+}
+
+fn parse_input_part_two(input: &Input) -> Result<Vec<Calculation>, AocError> {
     let _t = Timer::start("Parsing file for part two");
-    let mut source = source.to_owned();
+    let (lines, operators) = split_worksheet(input)?;
     // Extract the operators in the last line, reversed for later logic
-    let operators: Vec<Operator> = parse_operators(&source.pop().expect("File Empty?"))
-        .into_iter()
-        .rev()
-        .collect();
-
-    // Create a flat byte grid for speed
-    let row_count = source.len();
-    let max_len = source
-        .iter()
-        .map(std::string::String::len)
-        .max()
-        .unwrap_or(0);
-    let mut grid = vec![b' '; row_count * max_len];
-
-    for (r, line) in source.iter().enumerate() {
-        let bytes = line.as_bytes();
-        let start = r * max_len;
-        // Copy the line bytes into our flat grid
-        grid[start..start + bytes.len()].copy_from_slice(bytes);
-    }
+    let operators: Vec<Operator> = operators.into_iter().rev().collect();
+
+    // View the numbers as a byte grid for speed, padding short lines with spaces
+    let grid = ByteGrid::new(lines.iter().map(|line| line.as_bytes()).collect());
+    let row_count = grid.height();
+    let max_len = grid.width();
 
     // Find dividers (columns that are only spaces)
     let mut dividers: Vec<usize> = vec![0];
     for col in 0..max_len {
         let mut is_divider = true;
         for row in 0..row_count {
-            if grid[row * max_len + col] != b' ' {
+            if grid.get_or(row, col, b' ') != b' ' {
                 is_divider = false;
                 break;
             }
@@ -87,7 +84,7 @@ fn parse_input_part_two(source: &[String]) -> Vec<Calculation> {
         for col in (left..right).rev() {
             num_buf.clear();
             for row in 0..row_count {
-                let byte = grid[row * max_len + col];
+                let byte = grid.get_or(row, col, b' ');
                 if byte != b' ' {
                     num_buf.push(byte as char);
                 }
@@ -105,7 +102,7 @@ fn parse_input_part_two(source: &[String]) -> Vec<Calculation> {
         }
     }
 
-    operators
+    Ok(operators
         .into_iter()
         .enumerate()
         .map(|(i, operator)| {
@@ -117,29 +114,31 @@ fn parse_input_part_two(source: &[String]) -> Vec<Calculation> {
             };
             Calculation { operator, values }
         })
-        .collect()
+        .collect())
 }
 
-fn parse_input_part_one(source: &[String]) -> Vec<Calculation> {
+fn parse_input_part_one(input: &Input) -> Result<Vec<Calculation>, AocError> {
     let _t = Timer::start("Parsing source for part one");
-    let mut source = source.to_owned();
-    let operators = parse_operators(&source.pop().expect("File empty"));
+    let (lines, operators) = split_worksheet(input)?;
 
     let mut values_grid: Vec<Vec<isize>> = vec![vec![]; operators.len()];
 
-    for line in source {
-        for (idx, val) in split_line(&line).into_iter().enumerate() {
+    for (line_idx, line) in lines.iter().enumerate() {
+        for (idx, val) in split_line(line).into_iter().enumerate() {
             if idx < values_grid.len() {
-                values_grid[idx].push(val.parse().unwrap());
+                values_grid[idx].push(
+                    parse_number(line, val)
+                        .map_err(|e| e.in_file(input.path(), line_idx + 1, line))?,
+                );
             }
         }
     }
 
-    operators
+    Ok(operators
         .into_iter()
         .zip(values_grid)
         .map(|(operator, values)| Calculation { operator, values })
-        .collect()
+        .collect())
 }
 
 fn calculate(calc: &Calculation) -> isize {
@@ -154,37 +153,46 @@ fn calculate(calc: &Calculation) -> isize {
     })
 }
 
-fn part_one(calculations: Vec<Calculation>) -> isize {
+fn part_one(calculations: &[Calculation]) -> isize {
     let _t = Timer::start("Part One");
     let mut sum = 0;
     for calculation in calculations {
-        sum += calculate(&calculation);
-        debug_println!("Current sum {sum}");
+        sum += calculate(calculation);
+        debug!("Current sum {sum}");
     }
-    println!("Part One Result: {sum}");
     sum
 }
 
-fn part_two(calculations: Vec<Calculation>) -> isize {
+fn part_two(calculations: &[Calculation]) -> isize {
     let _t = Timer::start("Part Two");
     let mut sum = 0;
     for calculation in calculations {
-        sum += calculate(&calculation);
-        debug_println!("Current sum {sum}");
+        sum += calculate(calculation);
+        debug!("Current sum {sum}");
     }
-    println!("Part Two Result: {sum}");
     sum
 }
 
-fn main() {
-    let _t = Timer::start("Day 6");
-    let file_timer = Timer::start("Reading file");
-    let source = read_file("./data/day6.txt");
-    std::mem::drop(file_timer);
-    let calculations = parse_input_part_one(&source);
-    part_one(calculations);
-    let calculations = parse_input_part_two(&source);
-    part_two(calculations);
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    // The two parts read the worksheet differently, so both readings are parsed up front
+    type Input = (Vec<Calculation>, Vec<Calculation>);
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        Ok((parse_input_part_one(input)?, parse_input_part_two(input)?))
+    }
+
+    fn part_one((calculations, _): &Self::Input) -> isize {
+        part_one(calculations)
+    }
+
+    fn part_two((_, calculations): &Self::Input) -> isize {
+        part_two(calculations)
+    }
 }
 
 #[cfg(test)]
@@ -195,7 +203,7 @@ mod tests {
     #[rstest]
     fn test_split_line() {
         let line = "  6 98  215 314";
-        assert_eq!(split_line(&line), vec!["6", "98", "215", "314"]);
+        assert_eq!(split_line(line), vec!["6", "98", "215", "314"]);
     }
 
     #[rstest]
@@ -210,15 +218,24 @@ mod tests {
 
     #[rstest]
     fn test_part_one_with_example_data() {
-        let source = read_file("./data/day6_test");
-        let calculations = parse_input_part_one(&source);
-        assert_eq!(part_one(calculations), 4277556)
+        let source = Input::read("./data/day6_test").unwrap();
+        let calculations = parse_input_part_one(&source).unwrap();
+        assert_eq!(part_one(&calculations), 4277556)
     }
 
     #[rstest]
     fn test_part_two_with_example_data() {
-        let source = read_file("./data/day6_test");
-        let calculations = parse_input_part_two(&source);
-        assert_eq!(part_two(calculations), 3263827);
+        let source = Input::read("./data/day6_test").unwrap();
+        let calculations = parse_input_part_two(&source).unwrap();
+        assert_eq!(part_two(&calculations), 3263827);
+    }
+
+    #[rstest]
+    fn test_unknown_operator() {
+        let source = Input::from_string("worksheet", "1 2\n+ %\n");
+        match parse_input_part_one(&source) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 3)),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
     }
 }
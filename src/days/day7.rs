@@ -0,0 +1,197 @@
+use std::fmt;
+
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Contents {
+    Emitter,
+    Beam,
+    Splitter,
+    Space,
+}
+
+impl fmt::Display for Contents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Contents::Beam => '|',
+            Contents::Emitter => 'S',
+            Contents::Space => '.',
+            Contents::Splitter => '^',
+        };
+        write!(f, "{c}")
+    }
+}
+
+pub type Map = Grid<Contents>;
+
+fn parse_input(input: &Input) -> Result<Map, AocError> {
+    let filename = input.path();
+    let _t = Timer::start(format!("Parsing file {filename}"));
+    let map = Grid::parse(input, |c| match c {
+        '.' => Some(Contents::Space),
+        '^' => Some(Contents::Splitter),
+        '|' => Some(Contents::Beam),
+        'S' => Some(Contents::Emitter),
+        _ => None,
+    })?;
+    if map.height() == 0 || map.width() == 0 {
+        return Err(AocError::parse(filename, 1, 1, "", "Input is empty"));
+    }
+    // The beams start from a single emitter on the top row, and splitters send them left and
+    // right, so can't sit on either edge
+    for (row, (line_no, line)) in input.numbered_lines().enumerate() {
+        let error = |column: usize, reason: &str| {
+            Err(AocError::parse(filename, line_no, column + 1, line, reason))
+        };
+        let mut emitters = line.match_indices('S').map(|(column, _)| column);
+        match (row, emitters.next(), emitters.next()) {
+            (0, None, _) => return error(0, "Expected an emitter S in the first row"),
+            (0, Some(_), Some(column)) => {
+                return error(column, "Expected only one emitter S in the first row");
+            }
+            (1.., Some(column), _) => {
+                return error(column, "The emitter S has to be in the first row");
+            }
+            _ => (),
+        }
+        if line.starts_with('^') {
+            return error(0, "Splitters can't be on the edge of the map");
+        }
+        if line.ends_with('^') {
+            return error(line.len() - 1, "Splitters can't be on the edge of the map");
+        }
+    }
+    Ok(map)
+}
+
+fn part_one(map: &Map) -> usize {
+    let _t = Timer::start("Part One");
+    let mut map = map.clone();
+    let mut count = 0;
+
+    for r_idx in 0..map.height() - 1 {
+        debug!("Current state:\n{map}");
+        for c_idx in 0..map.width() {
+            match map[(r_idx, c_idx)] {
+                Contents::Space | Contents::Splitter => (),
+                Contents::Beam | Contents::Emitter => {
+                    // I've validated that no splitter appears on the edges of the map.
+                    // Emitter only exists on first row.  Only Space and Splitter could exist in the next row
+                    match map[(r_idx + 1, c_idx)] {
+                        Contents::Space => map[(r_idx + 1, c_idx)] = Contents::Beam,
+                        Contents::Splitter => {
+                            count += 1;
+                            assert!(
+                                map[(r_idx + 1, c_idx - 1)] != Contents::Splitter,
+                                "Splitter at r_idx+1, c_idx-1, where we were going to put a beam"
+                            );
+                            assert!(
+                                map[(r_idx + 1, c_idx + 1)] != Contents::Splitter,
+                                "Splitter at r_idx+1, c_idx+1, where we were going to put a beam"
+                            );
+                            map[(r_idx + 1, c_idx - 1)] = Contents::Beam;
+                            map[(r_idx + 1, c_idx + 1)] = Contents::Beam;
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+    debug!("Final state:\n{map}");
+    count
+}
+
+fn part_two(map: &Map) -> usize {
+    let _t = Timer::start("Part Two");
+    let mut count_map = Grid::new(map.width(), map.height(), 0_usize);
+    // Set the emitter to 1.  Parsing made sure there's one in the first line
+    for (c_idx, contents) in map.row(0).iter().enumerate() {
+        if *contents == Contents::Emitter {
+            count_map[(0, c_idx)] = 1;
+        }
+    }
+    for r_idx in 1..map.height() {
+        for c_idx in 0..map.width() {
+            let prev_count = count_map[(r_idx - 1, c_idx)];
+            match map[(r_idx, c_idx)] {
+                Contents::Splitter => {
+                    // I've validated in the data source that no splitter appears at the edges
+                    count_map[(r_idx, c_idx - 1)] += prev_count;
+                    count_map[(r_idx, c_idx + 1)] += prev_count;
+                }
+                _ => {
+                    count_map[(r_idx, c_idx)] += prev_count;
+                }
+            }
+        }
+    }
+    count_map.row(map.height() - 1).iter().sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        let source = parse_input(input)?;
+        debug!("{source}");
+        Ok(source)
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one_from_sample_data() {
+        let source: Map = parse_input(&Input::read("./data/day7_test").unwrap()).unwrap();
+        assert_eq!(part_one(&source), 21);
+    }
+
+    #[rstest]
+    fn test_part_two_from_sample_data() {
+        let source: Map = parse_input(&Input::read("./data/day7_test").unwrap()).unwrap();
+        assert_eq!(part_two(&source), 40);
+    }
+
+    #[rstest]
+    #[case("", 1, 1, "Input is empty")]
+    #[case("...\n.^.\n", 1, 1, "Expected an emitter S in the first row")]
+    #[case(".S.S.\n.....\n", 1, 4, "Expected only one emitter S in the first row")]
+    #[case("..S..\n..S..\n", 2, 3, "The emitter S has to be in the first row")]
+    #[case("..S..\n....^\n", 2, 5, "Splitters can't be on the edge of the map")]
+    fn test_bad_maps_are_rejected(
+        #[case] text: &str,
+        #[case] want_line: usize,
+        #[case] want_column: usize,
+        #[case] want: &str,
+    ) {
+        match parse_input(&Input::from_string("test", text)) {
+            Err(AocError::Parse {
+                line,
+                column,
+                reason,
+                ..
+            }) => {
+                assert_eq!((line, column), (want_line, want_column));
+                assert_eq!(reason, want);
+            }
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::*;
+
+fn parse_input(input: &Input) -> Result<Vec<Point3<isize>>, AocError> {
+    let filename = input.path();
+    let _t = Timer::start(format!("Parsing file {filename}"));
+    let boxes = parse_lines(filename, input.numbered_lines(), Point3::parse)?;
+    // Part one multiplies the sizes of the three largest circuits
+    if boxes.len() < MIN_BOXES {
+        return Err(AocError::parse(
+            filename,
+            input.lines().count() + 1,
+            1,
+            "",
+            format!(
+                "Expected at least {MIN_BOXES} junction boxes, found {}",
+                boxes.len()
+            ),
+        ));
+    }
+    Ok(boxes)
+}
+
+fn part_two(source: &[Point3<isize>]) -> isize {
+    let _t = Timer::start("Part Two");
+    let n = source.len();
+
+    let mut edges: Vec<(isize, usize, usize)> = (0..n)
+        .flat_map(|i| {
+            (i + 1..n).map(move |j| {
+                let d = source[i].distance_squared(&source[j]);
+                (d, i, j)
+            })
+        })
+        .collect();
+
+    // I think we need to sort here?
+    edges.sort_unstable_by_key(|a| a.0);
+
+    // Build the disjoint
+    let mut dsu = UnionFind::new(n);
+    let mut count = 0;
+    let mut answer: isize = 0;
+    for (_, i, j) in edges {
+        count += 1;
+        debug!("#### Adding union between {i} and {j}");
+        dsu.union(i, j);
+        if dsu.num_groups() == 1 {
+            // We can quit!
+            debug!("Stopped after {count} connections");
+            debug!(
+                "Final connection was between {:?} and {:?}",
+                source[i], source[j]
+            );
+            answer = source[i].x * source[j].x;
+            break;
+        }
+    }
+
+    answer
+}
+
+/// `None` if the connections leave fewer than three circuits
+fn part_one(source: &[Point3<isize>], pairs_to_connect: usize) -> Option<usize> {
+    let _t = Timer::start("Part One");
+    let n = source.len();
+
+    let mut edges: Vec<(isize, usize, usize)> = (0..n)
+        .flat_map(|i| {
+            (i + 1..n).map(move |j| {
+                let d = source[i].distance_squared(&source[j]);
+                (d, i, j)
+            })
+        })
+        .collect();
+    debug!("{:?}", edges);
+
+    // Don't do a full sort, QuickSelect will help us here.  We're picking the nth smallest by key.
+    if pairs_to_connect < edges.len() {
+        edges.select_nth_unstable_by_key(pairs_to_connect, |a| a.0);
+        edges.truncate(pairs_to_connect);
+    }
+
+    // Build the disjoint
+    let mut dsu = UnionFind::new(n);
+    for (_, i, j) in edges {
+        dsu.union(i, j);
+    }
+
+    // Then get the result
+    let mut biggest_sizes = dsu.group_sizes();
+    debug!("Found {} distinct groups.", biggest_sizes.len());
+    debug!("Group sizes: {:?}", biggest_sizes);
+    biggest_sizes.sort_unstable();
+    debug!("Sorted group: {:?}", biggest_sizes);
+    if biggest_sizes.len() < 3 {
+        warn!(
+            "Connecting {pairs_to_connect} pairs left only {} circuits",
+            biggest_sizes.len()
+        );
+        return None;
+    }
+    // take the three largest values, multiply them together
+    Some(biggest_sizes.iter().rev().take(3).product())
+}
+
+// The puzzle connects the 1000 closest pairs of junction boxes, except in the sample which only
+// has 20 boxes and connects 10 of them.
+const PAIRS_TO_CONNECT: usize = 1000;
+const SAMPLE_PAIRS_TO_CONNECT: usize = 10;
+const MIN_BOXES: usize = 3;
+
+pub struct Junctions {
+    boxes: Vec<Point3<isize>>,
+    pairs_to_connect: usize,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Junctions;
+    type PartOne = Option<usize>;
+    type PartTwo = isize;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        Ok(Junctions {
+            boxes: parse_input(input)?,
+            pairs_to_connect: PAIRS_TO_CONNECT,
+        })
+    }
+
+    fn parse_sample(input: &Input) -> Result<Self::Input, AocError> {
+        Ok(Junctions {
+            boxes: parse_input(input)?,
+            pairs_to_connect: SAMPLE_PAIRS_TO_CONNECT,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<usize> {
+        part_one(&input.boxes, input.pairs_to_connect)
+    }
+
+    fn part_two(input: &Self::Input) -> isize {
+        part_two(&input.boxes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day8_test").unwrap()).unwrap();
+        assert_eq!(part_one(&source, SAMPLE_PAIRS_TO_CONNECT), Some(40));
+    }
+
+    #[rstest]
+    fn test_pairs_to_connect() {
+        let sample = Input::read("./data/day8_test").unwrap();
+        assert_eq!(Day8::parse_sample(&sample).unwrap().pairs_to_connect, 10);
+        // However small the real input is, it connects the full 1000 pairs
+        assert_eq!(Day8::parse(&sample).unwrap().pairs_to_connect, 1000);
+    }
+
+    #[rstest]
+    fn test_part_two_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day8_test").unwrap()).unwrap();
+        assert_eq!(part_two(&source), 25272);
+    }
+
+    #[rstest]
+    fn test_too_few_circuits() {
+        let input = Input::from_string("test", "0,0,0\n1,0,0\n9,0,0\n20,0,0\n");
+        let source = parse_input(&input).unwrap();
+        assert_eq!(part_one(&source, 1), Some(2));
+        // Only the box at 20 is left out on its own
+        assert_eq!(part_one(&source, 3), None);
+        assert_eq!(part_two(&source), 180);
+    }
+
+    #[rstest]
+    #[case("", 1, "Expected at least 3 junction boxes, found 0")]
+    #[case("0,0,0\n1,1,1\n", 3, "Expected at least 3 junction boxes, found 2")]
+    fn test_too_few_boxes(#[case] text: &str, #[case] want_line: usize, #[case] want: &str) {
+        match parse_input(&Input::from_string("test", text)) {
+            Err(AocError::Parse { line, reason, .. }) => {
+                assert_eq!((line, reason.as_str()), (want_line, want))
+            }
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+}
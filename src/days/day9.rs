@@ -0,0 +1,180 @@
+use std::fmt;
+
+use indicatif::{ParallelProgressIterator, ProgressIterator};
+use rayon::prelude::*;
+
+use crate::*;
+
+fn rectangle_area(a: &Point2<i64>, b: &Point2<i64>) -> usize {
+    let sides = a.abs_diff(b);
+    let answer = ((sides.x + 1) * (sides.y + 1)) as usize;
+    debug!("Area of rectangle made from {a} and {b} is {answer}");
+    answer
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    points: Vec<Point2<i64>>,
+    /// The loop the red tiles make, joined up by green tiles
+    shape: RectilinearPolygon,
+    /// The lowest x and y of any red tile
+    min: Point2<i64>,
+    /// The highest x and y of any red tile
+    max: Point2<i64>,
+}
+
+impl Map {
+    fn new(points: Vec<Point2<i64>>) -> Result<Self, NotRectilinear> {
+        let (min, max) = Point2::bounding_box(&points).unwrap_or_default();
+        debug!("Bounding box from {min} to {max}");
+        let shape = RectilinearPolygon::new(points.clone())?;
+        Ok(Self {
+            points,
+            shape,
+            min,
+            max,
+        })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Do not use for large maps!  This eats up ram!
+        let size = self.max - self.min;
+        let mut temp_output = Grid::new(size.y as usize + 1, size.x as usize + 1, '.');
+        for point in self.points.iter().progress() {
+            let offset = *point - self.min;
+            temp_output[(offset.x as usize, offset.y as usize)] = '#';
+        }
+        write!(f, "{temp_output}")
+    }
+}
+
+fn parse_input(input: &Input) -> Result<Map, AocError> {
+    let filename = input.path();
+    let _t = Timer::start(format!("Parsing file: {filename}"));
+    let points = parse_lines(filename, input.numbered_lines(), Point2::parse)?;
+    // Each rectangle needs a red tile in two opposite corners
+    if points.len() < 2 {
+        return Err(AocError::parse(
+            filename,
+            input.lines().count() + 1,
+            1,
+            "",
+            format!("Expected at least 2 red tiles, found {}", points.len()),
+        ));
+    }
+    Map::new(points).map_err(|e| {
+        // Vertices are numbered in the order they were read
+        let (line_no, line) = input.numbered_lines().nth(e.vertex).unwrap();
+        AocError::parse(
+            filename,
+            line_no,
+            1,
+            line,
+            "Expected each red tile to share a row or column with the next",
+        )
+    })
+}
+
+/// `None` if no rectangle fits inside the loop
+fn part_two(source: &Map) -> Option<usize> {
+    let _t = Timer::start("Part Two");
+    // Only rectangles that fit entirely inside the loop of red and green tiles count
+    let n = source.points.len();
+    let sizes: Vec<usize> = (0..n)
+        .into_par_iter()
+        .progress()
+        .flat_map(|f| {
+            (f + 1..n)
+                .filter(|&g| {
+                    source
+                        .shape
+                        .contains_rectangle(source.points[f], source.points[g])
+                })
+                .map(|g| rectangle_area(&source.points[f], &source.points[g]))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    sizes.into_iter().max()
+}
+
+/// `None` if there aren't two red tiles to make a rectangle from
+fn part_one(source: &Map) -> Option<usize> {
+    let _t = Timer::start("Part One");
+    let n = source.points.len();
+    let sizes: Vec<usize> = (0..n)
+        .flat_map(|f| {
+            (f + 1..n)
+                .map(|g| rectangle_area(&source.points[f], &source.points[g]))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    debug!("{:?}", sizes);
+    sizes.into_iter().max()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Map;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<usize> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day9_test").unwrap()).unwrap();
+        assert_eq!(part_one(&source), Some(50));
+    }
+
+    #[rstest]
+    fn test_part_two_from_sample_data() {
+        let source = parse_input(&Input::read("./data/day9_test").unwrap()).unwrap();
+        assert_eq!(part_two(&source), Some(24));
+    }
+
+    #[rstest]
+    fn test_diagonal_is_rejected() {
+        match parse_input(&Input::from_string("test", "1,1\n5,1\n3,4\n")) {
+            Err(AocError::Parse { line, reason, .. }) => assert_eq!(
+                (line, reason.as_str()),
+                (
+                    2,
+                    "Expected each red tile to share a row or column with the next"
+                )
+            ),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("", 1, "Expected at least 2 red tiles, found 0")]
+    #[case("3,4\n", 2, "Expected at least 2 red tiles, found 1")]
+    fn test_too_few_tiles(#[case] text: &str, #[case] want_line: usize, #[case] want: &str) {
+        match parse_input(&Input::from_string("test", text)) {
+            Err(AocError::Parse { line, reason, .. }) => {
+                assert_eq!((line, reason.as_str()), (want_line, want))
+            }
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+}
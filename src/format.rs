@@ -0,0 +1,324 @@
+use std::fmt;
+use std::time::Duration;
+
+/// How to split up the digits of a number, like the commas in 1,234,567
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grouping {
+    pub separator: char,
+    /// How many digits go in each group, counting left from the decimal point
+    pub size: usize,
+}
+
+impl Default for Grouping {
+    fn default() -> Self {
+        Grouping {
+            separator: ',',
+            size: 3,
+        }
+    }
+}
+
+impl Grouping {
+    /// Panics if `size` is zero
+    pub const fn new(separator: char, size: usize) -> Self {
+        assert!(size > 0, "Digits can't be grouped in zeros");
+        Grouping { separator, size }
+    }
+
+    /// Inspired by https://stackoverflow.com/questions/26998485/is-it-possible-to-print-a-number-formatted-with-thousand-separator-in-rust#comment136853740_67834588
+    /// then made generic, and avoiding most of the allocations.
+    /// Only the whole part is grouped, so `1234.5678` becomes `1,234.5678`.  Anything that
+    /// doesn't display as plain digits, like `NaN` or `1e30`, is left alone.
+    pub fn apply<T: fmt::Display>(&self, val: T) -> String {
+        let s = val.to_string();
+        // Make a note if the number is negative for later use, stripping the prefix if it is.
+        let (is_neg, num_str) = if let Some(stripped) = s.strip_prefix('-') {
+            (true, stripped)
+        } else {
+            (false, s.as_str())
+        };
+        let (whole, fraction) = match num_str.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (num_str, None),
+        };
+        if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+            return s;
+        }
+
+        let mut result = String::with_capacity(s.len() + (s.len() / self.size) * 4);
+
+        // Put the negative symbol back in
+        if is_neg {
+            result.push('-');
+        }
+
+        let offset = whole.len() % self.size;
+        if offset > 0 {
+            result.push_str(&whole[..offset]);
+        }
+
+        for (i, c) in whole[offset..].chars().enumerate() {
+            if i % self.size == 0 && (offset > 0 || i > 0) {
+                result.push(self.separator);
+            }
+            result.push(c);
+        }
+
+        if let Some(fraction) = fraction {
+            result.push('.');
+            result.push_str(fraction);
+        }
+        result
+    }
+}
+
+/// This method will print out numbers with thousands separators
+pub fn print_with_thousands_separator<T: fmt::Display>(val: T) -> String {
+    Grouping::default().apply(val)
+}
+
+/// A float to a fixed number of decimal places, with thousands separators
+pub fn format_decimal(value: f64, places: usize) -> String {
+    print_with_thousands_separator(format!("{value:.places$}"))
+}
+
+/// Divides `value` by `base` until it's small enough to show with one of `suffixes`, to one
+/// decimal place.  The check is done after rounding, so 999,999 is 1.0M rather than 1000.0k.
+fn scaled(value: f64, base: f64, suffixes: &[&str], space: &str) -> String {
+    let mut scaled = value;
+    let mut suffix = 0;
+    while (scaled.abs() * 10.0).round() / 10.0 >= base && suffix + 1 < suffixes.len() {
+        scaled /= base;
+        suffix += 1;
+    }
+    if suffix == 0 && value.fract() == 0.0 {
+        // Nothing to round, so don't pretend there's a fraction
+        format!("{value}{space}{}", suffixes[0])
+    } else {
+        format!("{scaled:.1}{space}{}", suffixes[suffix])
+    }
+}
+
+/// Shortens a count with SI suffixes, like `1.2M` for 1,234,567
+pub fn format_si(value: f64) -> String {
+    scaled(value, 1000.0, &["", "k", "M", "G", "T", "P", "E"], "")
+}
+
+/// A number of bytes in binary units, like `3.4 GiB`
+pub fn format_bytes(bytes: u64) -> String {
+    let suffixes = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    scaled(bytes as f64, 1024.0, &suffixes, " ")
+}
+
+/// A duration in the largest unit that keeps it readable, to a few significant figures, like
+/// `850ns`, `12.3µs`, `456.7ms`, `1.23s` or `2m 03.4s`
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    // Pick the unit by how the value rounds, so 999.96µs shows as 1.0ms rather than 1000.0µs
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 999_950 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 999_950_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else if nanos < 59_995_000_000 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else {
+        // Round to tenths of a second first, so the seconds can't show as 60.0
+        let tenths = (duration.as_millis() + 50) / 100;
+        let (minutes, tenths) = (tenths / 600, tenths % 600);
+        let seconds = format!("{:04.1}s", tenths as f64 / 10.0);
+        if minutes < 60 {
+            format!("{minutes}m {seconds}")
+        } else {
+            format!("{}h {:02}m {seconds}", minutes / 60, minutes % 60)
+        }
+    }
+}
+
+/// Which side of its column a cell is pushed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Rows of text lined up in columns, two spaces apart.  Rows can be shorter than the table is
+/// wide, leaving their last columns blank.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    align: Vec<Align>,
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// One column for each alignment given
+    pub fn new<A: Into<Vec<Align>>>(align: A) -> Self {
+        Table {
+            align: align.into(),
+            header: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets a first row that's lined up like the rest but never counted as one
+    pub fn header<I, S>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.header = Some(self.cells(cells));
+        self
+    }
+
+    /// Panics if the row has more cells than the table has columns
+    pub fn push<I, S>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let row = self.cells(cells);
+        self.rows.push(row);
+    }
+
+    fn cells<I, S>(&self, cells: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let cells: Vec<String> = cells.into_iter().map(Into::into).collect();
+        assert!(
+            cells.len() <= self.align.len(),
+            "{} cells don't fit in {} columns",
+            cells.len(),
+            self.align.len()
+        );
+        cells
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let all_rows = || self.header.iter().chain(&self.rows);
+        let mut widths = vec![0; self.align.len()];
+        for row in all_rows() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in all_rows() {
+            let mut line = String::new();
+            for (column, cell) in row.iter().enumerate() {
+                if column > 0 {
+                    line.push_str("  ");
+                }
+                let width = widths[column];
+                match self.align[column] {
+                    Align::Left => line.push_str(&format!("{cell:width$}")),
+                    Align::Right => line.push_str(&format!("{cell:>width$}")),
+                }
+            }
+            // Padding a left aligned last cell would only leave spaces on the end
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(-1234567_isize, "-1,234,567")]
+    #[case(9876543210_u64, "9,876,543,210")]
+    #[case(1000_i32, "1,000")]
+    #[case(255_u8, "255")]
+    #[case(-128_i16, "-128")] // Make sure we don't get "-,128"
+    #[case(1234567.891_f64, "1,234,567.891")]
+    #[case(-0.5_f64, "-0.5")]
+    #[case(f64::NAN, "NaN")]
+    #[case(f64::NEG_INFINITY, "-inf")]
+    fn test_thousands_separator<T: std::fmt::Display>(#[case] val: T, #[case] want: String) {
+        assert_eq!(print_with_thousands_separator(val), want);
+    }
+
+    #[rstest]
+    #[case(Grouping::new('_', 4), 123456789, "1_2345_6789")]
+    #[case(Grouping::new(' ', 3), 1234, "1 234")]
+    #[case(Grouping::new('.', 2), -12345, "-1.23.45")]
+    fn test_grouping(#[case] grouping: Grouping, #[case] val: i64, #[case] want: &str) {
+        assert_eq!(grouping.apply(val), want);
+    }
+
+    #[rstest]
+    #[case(1234.5678, 2, "1,234.57")]
+    #[case(-9876543.0, 0, "-9,876,543")]
+    #[case(0.125, 1, "0.1")]
+    fn test_format_decimal(#[case] value: f64, #[case] places: usize, #[case] want: &str) {
+        assert_eq!(format_decimal(value, places), want);
+    }
+
+    #[rstest]
+    #[case(999.0, "999")]
+    #[case(12.5, "12.5")]
+    #[case(1234.0, "1.2k")]
+    #[case(1_234_567.0, "1.2M")]
+    #[case(999_999.0, "1.0M")]
+    #[case(-45_600.0, "-45.6k")]
+    #[case(2e21, "2000.0E")]
+    fn test_format_si(#[case] value: f64, #[case] want: &str) {
+        assert_eq!(format_si(value), want);
+    }
+
+    #[rstest]
+    #[case(512, "512 B")]
+    #[case(1536, "1.5 KiB")]
+    #[case(3_650_722_202, "3.4 GiB")]
+    #[case(1_048_575, "1.0 MiB")]
+    fn test_format_bytes(#[case] bytes: u64, #[case] want: &str) {
+        assert_eq!(format_bytes(bytes), want);
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(850), "850ns")]
+    #[case(Duration::from_nanos(12_345), "12.3µs")]
+    #[case(Duration::from_nanos(999_960), "1.0ms")]
+    #[case(Duration::from_micros(456_700), "456.7ms")]
+    #[case(Duration::from_millis(1_234), "1.23s")]
+    #[case(Duration::from_millis(59_999), "1m 00.0s")]
+    #[case(Duration::from_millis(123_400), "2m 03.4s")]
+    #[case(Duration::from_secs(3_723), "1h 02m 03.0s")]
+    fn test_format_duration(#[case] duration: Duration, #[case] want: &str) {
+        assert_eq!(format_duration(duration), want);
+    }
+
+    #[rstest]
+    fn test_table() {
+        let mut table =
+            Table::new([Align::Left, Align::Right, Align::Left]).header(["Name", "µs", "Note"]);
+        table.push(["Parse", "1.5", "slow"]);
+        table.push(["Part One", "12.25"]);
+        assert_eq!(table.len(), 2);
+        assert_eq!(
+            table.to_string(),
+            "Name         µs  Note\nParse       1.5  slow\nPart One  12.25\n"
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "3 cells don't fit in 2 columns")]
+    fn test_table_row_too_long() {
+        Table::new([Align::Left, Align::Left]).push(["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,467 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{Grid, Point2};
+
+/// How `a -> b -> c` turns: positive for anticlockwise (with y pointing up), negative for
+/// clockwise, and zero when the three points are in a line
+fn turn(a: Point2<i64>, b: Point2<i64>, c: Point2<i64>) -> i64 {
+    let (ab, ac) = (b - a, c - a);
+    ab.x * ac.y - ab.y * ac.x
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// A straight line between two points, including both ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: Point2<i64>,
+    pub end: Point2<i64>,
+}
+
+impl Segment {
+    pub const fn new(start: Point2<i64>, end: Point2<i64>) -> Self {
+        Segment { start, end }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// Whether `point` lies on the segment, ends included
+    pub fn contains(&self, point: Point2<i64>) -> bool {
+        let (low, high) = Point2::bounding_box([&self.start, &self.end]).unwrap();
+        turn(self.start, self.end, point) == 0
+            && (low.x..=high.x).contains(&point.x)
+            && (low.y..=high.y).contains(&point.y)
+    }
+
+    /// Whether the two segments share any point, including just touching at an end or
+    /// overlapping along a line
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let turns = [
+            turn(self.start, self.end, other.start),
+            turn(self.start, self.end, other.end),
+            turn(other.start, other.end, self.start),
+            turn(other.start, other.end, self.end),
+        ];
+        // Each segment's ends are strictly either side of the other's line
+        if turns[0].signum() * turns[1].signum() < 0 && turns[2].signum() * turns[3].signum() < 0 {
+            return true;
+        }
+        // Otherwise they can only meet if an end of one lies on the other
+        other.contains(self.start)
+            || other.contains(self.end)
+            || self.contains(other.start)
+            || self.contains(other.end)
+    }
+}
+
+/// A simple polygon on integer coordinates, with its vertices in order around the edge in
+/// either direction.  The last vertex joins back up to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point2<i64>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2<i64>>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2<i64>] {
+        &self.vertices
+    }
+
+    /// Each side in turn, finishing with the one from the last vertex back to the first
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| Segment::new(self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Twice the enclosed area, which is always a whole number.  Uses the shoelace formula.
+    pub fn doubled_area(&self) -> i64 {
+        self.edges()
+            .map(|edge| edge.start.x * edge.end.y - edge.end.x * edge.start.y)
+            .sum::<i64>()
+            .abs()
+    }
+
+    /// How many integer points lie on the edges
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|edge| {
+                let d = edge.start.abs_diff(&edge.end);
+                gcd(d.x, d.y)
+            })
+            .sum()
+    }
+
+    /// How many integer points lie strictly inside, from Pick's theorem:
+    /// area = interior + boundary / 2 - 1
+    pub fn interior_points(&self) -> i64 {
+        (self.doubled_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// How many integer points lie inside or on the edges, which for a loop of grid cells is
+    /// every cell the loop encloses, the loop included
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Whether `point` is inside or on an edge
+    pub fn contains(&self, point: Point2<i64>) -> bool {
+        let mut inside = false;
+        for edge in self.edges() {
+            if edge.contains(point) {
+                return true;
+            }
+            // Count the edges crossed by a ray heading off in the +x direction.  Treating each
+            // edge as half open in y means a ray through a vertex is only counted once.
+            let (a, b) = (edge.start, edge.end);
+            if (a.y > point.y) != (b.y > point.y) && (turn(a, b, point) > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+/// Maps a set of coordinates onto `0..len()`, keeping their order, so a grid only needs a
+/// row or column for each value that matters rather than for every value in between
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Compressed {
+    /// Sorted, without repeats
+    values: Vec<i64>,
+}
+
+impl Compressed {
+    pub fn new<I: IntoIterator<Item = i64>>(values: I) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Compressed { values }
+    }
+
+    /// The position of `value`, if it's one of the coordinates
+    pub fn index(&self, value: i64) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    /// The coordinate at `index`.  Panics if it's out of range.
+    pub fn value(&self, index: usize) -> i64 {
+        self.values[index]
+    }
+
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Where `value` falls when the gaps between coordinates count too: odd slots are the
+    /// coordinates themselves, and even slots the open stretches before, between and after
+    /// them.  There are `2 * len() + 1` slots in all.
+    pub fn slot(&self, value: i64) -> usize {
+        match self.values.binary_search(&value) {
+            Ok(idx) => 2 * idx + 1,
+            Err(idx) => 2 * idx,
+        }
+    }
+
+    /// Whether `slot` is the gap between two coordinates that are next to each other, so has no
+    /// whole numbers in it
+    pub fn is_empty_gap(&self, slot: usize) -> bool {
+        slot.is_multiple_of(2)
+            && slot > 0
+            && slot < 2 * self.len()
+            && self.values[slot / 2] - self.values[slot / 2 - 1] == 1
+    }
+}
+
+/// The edge from `vertex` to the one after it is neither horizontal nor vertical
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotRectilinear {
+    pub vertex: usize,
+}
+
+impl fmt::Display for NotRectilinear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Edge from vertex {} is neither horizontal nor vertical",
+            self.vertex
+        )
+    }
+}
+
+impl Error for NotRectilinear {}
+
+/// A polygon with only horizontal and vertical edges, set up to answer whether rectangles fit
+/// inside it without drawing out every point.  The plane is cut up along each vertex's x and
+/// y, which leaves a grid of pieces that are each wholly inside or outside, with one row and
+/// column per distinct coordinate rather than per unit.
+#[derive(Debug, Clone)]
+pub struct RectilinearPolygon {
+    polygon: Polygon,
+    xs: Compressed,
+    ys: Compressed,
+    /// How many pieces outside the polygon are above and to the left of each slot, so any
+    /// block of slots can be checked in one go
+    outside_before: Grid<usize>,
+}
+
+impl RectilinearPolygon {
+    /// # Errors
+    ///
+    /// Returns `NotRectilinear` for the first edge that's diagonal
+    pub fn new(vertices: Vec<Point2<i64>>) -> Result<Self, NotRectilinear> {
+        let polygon = Polygon::new(vertices);
+        if let Some(vertex) = polygon
+            .edges()
+            .position(|edge| !edge.is_horizontal() && !edge.is_vertical())
+        {
+            return Err(NotRectilinear { vertex });
+        }
+        let xs = Compressed::new(polygon.vertices.iter().map(|vertex| vertex.x));
+        let ys = Compressed::new(polygon.vertices.iter().map(|vertex| vertex.y));
+
+        // Slots are (y, x), with an empty stretch all the way around the outside
+        let (height, width) = (2 * ys.len() + 1, 2 * xs.len() + 1);
+        let mut is_edge = Grid::new(width, height, false);
+        for edge in polygon.edges() {
+            let (start, end) = (
+                (ys.slot(edge.start.y), xs.slot(edge.start.x)),
+                (ys.slot(edge.end.y), xs.slot(edge.end.x)),
+            );
+            for row in start.0.min(end.0)..=start.0.max(end.0) {
+                for column in start.1.min(end.1)..=start.1.max(end.1) {
+                    is_edge[(row, column)] = true;
+                }
+            }
+        }
+        let outside = is_edge.flood_fill((0, 0), |&edge| !edge);
+
+        // A gap with no points in it can't stop a rectangle fitting, even if it's outside
+        let mut outside_before = Grid::new(width + 1, height + 1, 0);
+        for ((row, column), &out) in outside.iter() {
+            let counts = out && !ys.is_empty_gap(row) && !xs.is_empty_gap(column);
+            outside_before[(row + 1, column + 1)] = usize::from(counts)
+                + outside_before[(row, column + 1)]
+                + outside_before[(row + 1, column)]
+                - outside_before[(row, column)];
+        }
+        Ok(RectilinearPolygon {
+            polygon,
+            xs,
+            ys,
+            outside_before,
+        })
+    }
+
+    pub fn polygon(&self) -> &Polygon {
+        &self.polygon
+    }
+
+    /// Whether `point` is inside or on an edge
+    pub fn contains(&self, point: Point2<i64>) -> bool {
+        self.contains_rectangle(point, point)
+    }
+
+    /// Whether the whole rectangle with opposite corners `a` and `b` is inside, where touching
+    /// or running along an edge still counts as inside
+    pub fn contains_rectangle(&self, a: Point2<i64>, b: Point2<i64>) -> bool {
+        let (low, high) = Point2::bounding_box([&a, &b]).unwrap();
+        let (top, left) = (self.ys.slot(low.y), self.xs.slot(low.x));
+        let (bottom, right) = (self.ys.slot(high.y) + 1, self.xs.slot(high.x) + 1);
+        let sums = &self.outside_before;
+        sums[(bottom, right)] + sums[(top, left)] == sums[(top, right)] + sums[(bottom, left)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn p(x: i64, y: i64) -> Point2<i64> {
+        Point2::new(x, y)
+    }
+
+    fn square() -> Polygon {
+        Polygon::new(vec![p(0, 0), p(4, 0), p(4, 4), p(0, 4)])
+    }
+
+    // A U shape, with a notch two wide cut up from y=0 to y=3 between x=2 and x=4
+    fn cup() -> Vec<Point2<i64>> {
+        vec![
+            p(0, 0),
+            p(2, 0),
+            p(2, 3),
+            p(4, 3),
+            p(4, 0),
+            p(6, 0),
+            p(6, 5),
+            p(0, 5),
+        ]
+    }
+
+    #[rstest]
+    fn test_area_and_pick() {
+        let square = square();
+        assert_eq!(square.doubled_area(), 32);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+        // Going round the other way gives the same answers
+        let mut vertices = square.vertices().to_vec();
+        vertices.reverse();
+        assert_eq!(Polygon::new(vertices).doubled_area(), 32);
+
+        let triangle = Polygon::new(vec![p(0, 0), p(4, 0), p(0, 2)]);
+        assert_eq!(triangle.doubled_area(), 8);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 1);
+    }
+
+    #[rstest]
+    #[case(p(2, 2), true)]
+    #[case(p(0, 0), true)]
+    #[case(p(4, 2), true)]
+    #[case(p(5, 2), false)]
+    #[case(p(-1, 4), false)]
+    #[case(p(2, 5), false)]
+    fn test_polygon_contains(#[case] point: Point2<i64>, #[case] want: bool) {
+        assert_eq!(square().contains(point), want);
+    }
+
+    #[rstest]
+    #[case(p(1, 1), true)]
+    #[case(p(3, 1), false)] // In the notch
+    #[case(p(3, 3), true)]
+    #[case(p(3, 4), true)]
+    #[case(p(7, 4), false)]
+    fn test_contains_with_notch(#[case] point: Point2<i64>, #[case] want: bool) {
+        assert_eq!(Polygon::new(cup()).contains(point), want);
+        assert_eq!(
+            RectilinearPolygon::new(cup()).unwrap().contains(point),
+            want
+        );
+    }
+
+    #[rstest]
+    #[case(Segment::new(p(0, 0), p(4, 4)), Segment::new(p(0, 4), p(4, 0)), true)]
+    #[case(Segment::new(p(0, 0), p(4, 0)), Segment::new(p(4, 0), p(4, 3)), true)]
+    #[case(Segment::new(p(0, 0), p(4, 0)), Segment::new(p(2, 0), p(6, 0)), true)]
+    #[case(Segment::new(p(0, 0), p(4, 0)), Segment::new(p(5, 0), p(6, 0)), false)]
+    #[case(Segment::new(p(0, 0), p(4, 0)), Segment::new(p(2, 1), p(2, 3)), false)]
+    #[case(Segment::new(p(0, 0), p(1, 1)), Segment::new(p(1, 0), p(2, -1)), false)]
+    fn test_segment_intersects(#[case] a: Segment, #[case] b: Segment, #[case] want: bool) {
+        assert_eq!(a.intersects(&b), want);
+        assert_eq!(b.intersects(&a), want);
+    }
+
+    #[rstest]
+    fn test_compressed() {
+        let xs = Compressed::new([30, 10, 20, 10]);
+        assert_eq!(xs.values(), [10, 20, 30]);
+        assert_eq!((xs.index(20), xs.index(25)), (Some(1), None));
+        assert_eq!(xs.value(2), 30);
+        let slots: Vec<usize> = [5, 10, 15, 20, 30, 99].map(|x| xs.slot(x)).to_vec();
+        assert_eq!(slots, [0, 1, 2, 3, 5, 6]);
+        let ys = Compressed::new([3, 4, 6]);
+        let empty: Vec<usize> = (0..7).filter(|&slot| ys.is_empty_gap(slot)).collect();
+        assert_eq!(empty, [2]);
+    }
+
+    #[rstest]
+    #[case(p(0, 0), p(2, 5), true)]
+    #[case(p(0, 3), p(6, 5), true)]
+    #[case(p(0, 0), p(6, 5), false)]
+    #[case(p(2, 0), p(4, 3), false)] // Just the notch, bounded by edges on three sides
+    #[case(p(1, 2), p(5, 2), false)] // A line across the notch
+    #[case(p(2, 3), p(4, 3), true)] // Along the bottom of the notch
+    #[case(p(6, 5), p(6, 0), true)]
+    #[case(p(5, 4), p(7, 4), false)]
+    fn test_contains_rectangle(#[case] a: Point2<i64>, #[case] b: Point2<i64>, #[case] want: bool) {
+        let cup = RectilinearPolygon::new(cup()).unwrap();
+        assert_eq!(cup.contains_rectangle(a, b), want);
+        assert_eq!(cup.contains_rectangle(b, a), want);
+    }
+
+    // Notches one wide have no points inside them, between edges that are both in the polygon
+    fn thin_notch() -> Vec<Point2<i64>> {
+        vec![
+            p(0, 0),
+            p(2, 0),
+            p(2, 3),
+            p(3, 3),
+            p(3, 0),
+            p(6, 0),
+            p(6, 5),
+            p(0, 5),
+        ]
+    }
+
+    // A pocket outside the polygon, reached through a neck one wide from x=3 to x=4
+    fn bottle() -> Vec<Point2<i64>> {
+        vec![
+            p(0, 0),
+            p(3, 0),
+            p(3, 2),
+            p(1, 2),
+            p(1, 4),
+            p(6, 4),
+            p(6, 2),
+            p(4, 2),
+            p(4, 0),
+            p(8, 0),
+            p(8, 6),
+            p(0, 6),
+        ]
+    }
+
+    #[rstest]
+    #[case(cup())]
+    #[case(thin_notch())]
+    #[case(bottle())]
+    fn test_contains_rectangle_matches_points(#[case] vertices: Vec<Point2<i64>>) {
+        let polygon = Polygon::new(vertices.clone());
+        let rectilinear = RectilinearPolygon::new(vertices).unwrap();
+        let points: Vec<Point2<i64>> = (-1..=9)
+            .flat_map(|y| (-1..=9).map(move |x| p(x, y)))
+            .collect();
+        for a in &points {
+            for b in points.iter().filter(|b| b.x >= a.x && b.y >= a.y) {
+                let want = (a.y..=b.y).all(|y| (a.x..=b.x).all(|x| polygon.contains(p(x, y))));
+                assert_eq!(
+                    rectilinear.contains_rectangle(*a, *b),
+                    want,
+                    "{a:?} to {b:?}"
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_not_rectilinear() {
+        let err = RectilinearPolygon::new(vec![p(0, 0), p(4, 0), p(0, 2)]).unwrap_err();
+        assert_eq!(err, NotRectilinear { vertex: 1 });
+        assert_eq!(
+            err.to_string(),
+            "Edge from vertex 1 is neither horizontal nor vertical"
+        );
+    }
+}
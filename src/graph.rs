@@ -0,0 +1,414 @@
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::Interner;
+
+/// A directed graph over named nodes.  Names are interned, so everything past building the
+/// graph works with dense ids and plain `Vec`s.
+#[derive(Debug, Clone)]
+pub struct Graph<K> {
+    names: Interner<K>,
+    forward: Vec<Vec<usize>>,
+    reverse: Vec<Vec<usize>>,
+}
+
+/// The graph wasn't acyclic.  Holds the ids around one of the cycles, in edge order, starting
+/// from the lowest and without repeating it at the end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub nodes: Vec<usize>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cycle through ")?;
+        for id in &self.nodes {
+            write!(f, "{id} -> ")?;
+        }
+        write!(f, "{}", self.nodes[0])
+    }
+}
+
+impl Error for Cycle {}
+
+impl<K> Default for Graph<K> {
+    fn default() -> Self {
+        Graph {
+            names: Interner::default(),
+            forward: Vec::new(),
+            reverse: Vec::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Graph<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id for `name`, adding it without any edges if it's new
+    pub fn add_node(&mut self, name: K) -> usize {
+        let id = self.names.intern(name);
+        if id == self.forward.len() {
+            self.forward.push(Vec::new());
+            self.reverse.push(Vec::new());
+        }
+        id
+    }
+
+    /// Adds an edge, and either node if it's new, returning both ids.  Adding the same edge
+    /// twice gives two parallel edges.
+    pub fn add_edge(&mut self, from: K, to: K) -> (usize, usize) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.forward[from].push(to);
+        self.reverse[to].push(from);
+        (from, to)
+    }
+
+    /// The id for `name`, if it's in the graph
+    pub fn id<Q>(&self, name: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.names.get(name)
+    }
+}
+
+impl<K> Graph<K> {
+    /// Panics if `id` isn't in the graph
+    pub fn name(&self, id: usize) -> &K {
+        self.names.key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.forward.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forward.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.forward.iter().map(Vec::len).sum()
+    }
+
+    /// The nodes `id` has edges to
+    pub fn successors(&self, id: usize) -> &[usize] {
+        &self.forward[id]
+    }
+
+    /// The nodes with edges to `id`
+    pub fn predecessors(&self, id: usize) -> &[usize] {
+        &self.reverse[id]
+    }
+
+    /// Every node, ordered so that edges only ever point forwards.  Nodes with nothing
+    /// forcing their order come out in id order.
+    ///
+    /// # Errors
+    ///
+    /// Returns one of the cycles that makes an order impossible
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Cycle> {
+        let mut in_degree: Vec<usize> = self.reverse.iter().map(Vec::len).collect();
+        let mut ready: VecDeque<usize> = (0..self.len()).filter(|&id| in_degree[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+            for &next in &self.forward[id] {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+        // Everything left over still has a predecessor that's left over, so walking backwards
+        // through those has to come round to a node it has already seen
+        let mut seen_at = vec![usize::MAX; self.len()];
+        let mut walk = Vec::new();
+        let mut id = (0..self.len()).find(|&id| in_degree[id] > 0).unwrap();
+        while seen_at[id] == usize::MAX {
+            seen_at[id] = walk.len();
+            walk.push(id);
+            id = *self.reverse[id]
+                .iter()
+                .find(|&&previous| in_degree[previous] > 0)
+                .unwrap();
+        }
+        let mut nodes = walk.split_off(seen_at[id]);
+        // Walked against the edges, so turn it round
+        nodes.reverse();
+        let lowest = (0..nodes.len()).min_by_key(|&i| nodes[i]).unwrap();
+        nodes.rotate_left(lowest);
+        Err(Cycle { nodes })
+    }
+
+    /// Whether each node can be reached by following edges from `start`, which counts itself
+    pub fn reachable_from(&self, start: usize) -> Vec<bool> {
+        Self::reach(&self.forward, start)
+    }
+
+    /// Whether each node can reach `target` by following edges, which counts itself
+    pub fn reaching(&self, target: usize) -> Vec<bool> {
+        Self::reach(&self.reverse, target)
+    }
+
+    fn reach(edges: &[Vec<usize>], start: usize) -> Vec<bool> {
+        let mut seen = vec![false; edges.len()];
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            for &next in &edges[id] {
+                if !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Groups of nodes that can all reach each other, using Tarjan's algorithm.  Every node is
+    /// in exactly one group, with members in ascending order.  Groups come out in reverse
+    /// topological order, so nothing has an edge into a group listed before its own.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+        // Done with an explicit stack of (node, next successor to look at), rather than
+        // recursively, so long chains can't overflow the real stack
+        let mut work: Vec<(usize, usize)> = Vec::new();
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+            work.push((root, 0));
+            while let Some((id, child)) = work.last_mut() {
+                let id = *id;
+                if index[id] == UNVISITED {
+                    index[id] = next_index;
+                    low[id] = next_index;
+                    next_index += 1;
+                    stack.push(id);
+                    on_stack[id] = true;
+                }
+                if let Some(&next) = self.forward[id].get(*child) {
+                    *child += 1;
+                    if index[next] == UNVISITED {
+                        work.push((next, 0));
+                    } else if on_stack[next] {
+                        low[id] = low[id].min(index[next]);
+                    }
+                    continue;
+                }
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[id]);
+                }
+                if low[id] == index[id] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == id {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// How many distinct routes lead from `from` to `to`, counting each parallel edge
+    /// separately.  A node on its own is one route to itself.  Each node's count is worked out
+    /// once, in topological order, so this is linear in the size of the graph however many
+    /// routes there are.
+    ///
+    /// Returns `None` if a cycle sits on some route, making the number infinite
+    pub fn count_paths(&self, from: usize, to: usize) -> Option<usize> {
+        let after = self.reachable_from(from);
+        let before = self.reaching(to);
+        if !after[to] {
+            return Some(0);
+        }
+        // Only nodes on some route between the two matter
+        let on_route = |id: usize| after[id] && before[id];
+        let mut in_degree: Vec<usize> = (0..self.len())
+            .map(|id| {
+                if on_route(id) {
+                    self.reverse[id].iter().filter(|&&p| on_route(p)).count()
+                } else {
+                    0
+                }
+            })
+            .collect();
+        if in_degree[from] > 0 {
+            // Something on a route leads back round to the start
+            return None;
+        }
+        let mut counts = vec![0; self.len()];
+        counts[from] = 1;
+        let mut ready = vec![from];
+        let mut processed = 0;
+        while let Some(id) = ready.pop() {
+            processed += 1;
+            for &next in self.forward[id].iter().filter(|&&next| on_route(next)) {
+                counts[next] += counts[id];
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+        let route_len = (0..self.len()).filter(|&id| on_route(id)).count();
+        (processed == route_len).then_some(counts[to])
+    }
+
+    /// One of the loops sitting on a route from `from` to `to`, as its nodes in ascending
+    /// order.  These are what make `count_paths` give up.
+    pub fn find_loop_between(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let after = self.reachable_from(from);
+        let before = self.reaching(to);
+        self.strongly_connected_components()
+            .into_iter()
+            .find(|component| {
+                let id = component[0];
+                after[id] && before[id] && (component.len() > 1 || self.forward[id].contains(&id))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn graph(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for &(from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    fn named(graph: &Graph<&'static str>, ids: &[usize]) -> Vec<&'static str> {
+        ids.iter().map(|&id| *graph.name(id)).collect()
+    }
+
+    #[rstest]
+    fn test_build() {
+        let mut graph = graph(&[("a", "b"), ("a", "c"), ("c", "b")]);
+        assert_eq!(graph.add_node("d"), 3);
+        assert_eq!(graph.add_node("a"), 0);
+        assert_eq!((graph.len(), graph.edge_count()), (4, 3));
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.id("z"), None);
+        assert_eq!(graph.successors(0), [1, 2]);
+        assert_eq!(graph.predecessors(1), [0, 2]);
+        assert!(graph.successors(3).is_empty());
+    }
+
+    #[rstest]
+    fn test_topological_sort() {
+        let graph = graph(&[("shirt", "tie"), ("tie", "jacket"), ("socks", "shoes")]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(
+            named(&graph, &order),
+            ["shirt", "socks", "tie", "shoes", "jacket"]
+        );
+    }
+
+    #[rstest]
+    fn test_topological_sort_cycle() {
+        // d leads into the cycle without being part of it
+        let graph = graph(&[("a", "b"), ("d", "b"), ("b", "c"), ("c", "a"), ("c", "e")]);
+        let cycle = graph.topological_sort().unwrap_err();
+        assert_eq!(named(&graph, &cycle.nodes), ["a", "b", "c"]);
+        assert_eq!(cycle.to_string(), "Cycle through 0 -> 1 -> 3 -> 0");
+    }
+
+    #[rstest]
+    fn test_reachability() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("d", "c")]);
+        assert_eq!(graph.reachable_from(1), [false, true, true, false]);
+        assert_eq!(graph.reaching(1), [true, true, false, false]);
+    }
+
+    #[rstest]
+    fn test_strongly_connected_components() {
+        let graph = graph(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "f"),
+        ]);
+        assert_eq!(
+            graph.strongly_connected_components(),
+            [vec![3, 4], vec![0, 1, 2], vec![5]]
+        );
+    }
+
+    #[rstest]
+    fn test_long_chain() {
+        let mut graph = Graph::new();
+        for id in 1..200_000_u32 {
+            graph.add_edge(id - 1, id);
+        }
+        assert_eq!(graph.strongly_connected_components().len(), 200_000);
+        assert_eq!(graph.count_paths(0, 199_999), Some(1));
+    }
+
+    #[rstest]
+    #[case(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")], "a", "d", Some(2))]
+    #[case(&[("a", "b"), ("a", "b"), ("b", "c")], "a", "c", Some(2))] // parallel edges
+    #[case(&[("a", "b"), ("c", "d")], "a", "d", Some(0))]
+    #[case(&[("a", "b")], "a", "a", Some(1))]
+    #[case(&[("a", "b"), ("b", "c"), ("c", "b")], "a", "c", None)]
+    #[case(&[("a", "b"), ("b", "a"), ("a", "c")], "a", "c", None)]
+    // The cycle doesn't lead to d, so it doesn't matter
+    #[case(&[("a", "d"), ("a", "b"), ("b", "c"), ("c", "b")], "a", "d", Some(1))]
+    fn test_count_paths(
+        #[case] edges: &[(&'static str, &'static str)],
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] want: Option<usize>,
+    ) {
+        let graph = graph(edges);
+        let (from, to) = (graph.id(from).unwrap(), graph.id(to).unwrap());
+        assert_eq!(graph.count_paths(from, to), want);
+    }
+
+    #[rstest]
+    #[case(&[("a", "b"), ("b", "c"), ("c", "b")], "a", "c", Some(vec!["b", "c"]))]
+    #[case(&[("a", "b"), ("b", "b"), ("b", "c")], "a", "c", Some(vec!["b"]))]
+    #[case(&[("a", "d"), ("a", "b"), ("b", "c"), ("c", "b")], "a", "d", None)]
+    #[case(&[("a", "b"), ("b", "c")], "a", "c", None)]
+    fn test_find_loop_between(
+        #[case] edges: &[(&'static str, &'static str)],
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] want: Option<Vec<&str>>,
+    ) {
+        let graph = graph(edges);
+        let (from, to) = (graph.id(from).unwrap(), graph.id(to).unwrap());
+        let found = graph.find_loop_between(from, to);
+        assert_eq!(found.map(|ids| named(&graph, &ids)), want);
+    }
+}
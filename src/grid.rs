@@ -0,0 +1,349 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{AocError, Input};
+
+/// The four orthogonal steps, as (row, column) offsets
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// All eight steps to the surrounding cells, as (row, column) offsets
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored flat in row-major order.  Positions are `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `cells` doesn't divide into rows of `width`
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            // Zero only divides zero, so an empty grid is the only one without width
+            cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Builds a grid from text, one row per line, turning each character into a cell with
+    /// `cell`.  Characters it returns `None` for, and lines of the wrong length, are errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Parse` pointing at the first bad character or short line
+    pub fn parse<F>(input: &Input, mut cell: F) -> Result<Self, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.bytes().len());
+        for (line_no, line) in input.numbered_lines() {
+            let before = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                let Some(value) = cell(c) else {
+                    return Err(AocError::parse(
+                        input.path(),
+                        line_no,
+                        column + 1,
+                        line,
+                        format!("Unknown character {c:?}"),
+                    ));
+                };
+                cells.push(value);
+            }
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(AocError::parse(
+                        input.path(),
+                        line_no,
+                        width.min(row_width) + 1,
+                        line,
+                        format!("Expected {width} columns like the first line, found {row_width}"),
+                    ));
+                }
+                Some(_) => (),
+            }
+        }
+        Ok(Grid::from_vec(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        (row < self.height && column < self.width)
+            .then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// Moves from `(row, column)` by a signed offset, if that stays on the grid
+    pub fn offset(
+        &self,
+        (row, column): (usize, usize),
+        (d_row, d_column): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(d_row)?;
+        let column = column.checked_add_signed(d_column)?;
+        (row < self.height && column < self.width).then_some((row, column))
+    }
+
+    /// The cell at a signed offset from `(row, column)`, if that stays on the grid
+    pub fn get_offset(&self, from: (usize, usize), by: (isize, isize)) -> Option<&T> {
+        self.offset(from, by).map(|position| &self[position])
+    }
+
+    /// The positions orthogonally next to `(row, column)` that are on the grid
+    pub fn neighbours4(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&by| self.offset((row, column), by))
+    }
+
+    /// The positions around `(row, column)`, diagonals included, that are on the grid
+    pub fn neighbours8(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&by| self.offset((row, column), by))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        // chunks_exact panics on zero, and an empty grid has no rows anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Panics if `column` is off the grid
+    pub fn column(&self, column: usize) -> impl ExactSizeIterator<Item = &T> {
+        assert!(column < self.width, "Column {column} is off the grid");
+        self.rows().map(move |row| &row[column])
+    }
+
+    /// Every position, in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx / width, idx % width))
+    }
+
+    /// Every cell along with its position, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, in row-major order, matching `predicate`
+    pub fn position<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<(usize, usize)> {
+        let idx = self.cells.iter().position(predicate)?;
+        Some((idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.get(row, column).unwrap_or_else(|| {
+            panic!(
+                "({row}, {column}) is off the {}x{} grid",
+                self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("({row}, {column}) is off the {height}x{width} grid"))
+    }
+}
+
+/// One line per row, with no separators between cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(&Input::from_string("test", "abc\ndef\n".to_owned()), Some).unwrap()
+    }
+
+    #[rstest]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.height(), grid.width(), grid.len()), (2, 3, 6));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[rstest]
+    #[case("ab\nc?\n", 2, 2, "Unknown character '?'")]
+    #[case("abc\nab\n", 2, 3, "Expected 3 columns like the first line, found 2")]
+    #[case("ab\nabc\n", 2, 3, "Expected 2 columns like the first line, found 3")]
+    fn test_parse_errors(
+        #[case] text: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] reason: &str,
+    ) {
+        let input = Input::from_string("test", text.to_owned());
+        let result = Grid::parse(&input, |c| c.is_ascii_alphabetic().then_some(c));
+        match result {
+            Err(AocError::Parse {
+                line: got_line,
+                column: got_column,
+                reason: got_reason,
+                ..
+            }) => assert_eq!(
+                (got_line, got_column, got_reason.as_str()),
+                (line, column, reason)
+            ),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_empty() {
+        let grid = Grid::parse(&Input::from_string("test", String::new()), Some).unwrap();
+        assert!(grid.is_empty());
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(0, 1), (1, 0)])]
+    #[case((1, 1), vec![(0, 1), (1, 0), (1, 2)])]
+    #[case((0, 2), vec![(0, 1), (1, 2)])]
+    fn test_neighbours4(#[case] (row, column): (usize, usize), #[case] want: Vec<(usize, usize)>) {
+        assert_eq!(sample().neighbours4(row, column).collect::<Vec<_>>(), want);
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(0, 1), (1, 0), (1, 1)])]
+    #[case((1, 1), vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)])]
+    fn test_neighbours8(#[case] (row, column): (usize, usize), #[case] want: Vec<(usize, usize)>) {
+        assert_eq!(sample().neighbours8(row, column).collect::<Vec<_>>(), want);
+    }
+
+    #[rstest]
+    #[case((1, 1), (-1, 1), Some('c'))]
+    #[case((0, 0), (-1, 0), None)]
+    #[case((1, 2), (0, 1), None)]
+    #[case((1, 2), (0, isize::MIN), None)]
+    fn test_get_offset(
+        #[case] from: (usize, usize),
+        #[case] by: (isize, isize),
+        #[case] want: Option<char>,
+    ) {
+        assert_eq!(sample().get_offset(from, by).copied(), want);
+    }
+
+    #[rstest]
+    fn test_rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).copied().collect::<String>(), "be");
+        assert_eq!(
+            grid.iter().map(|(position, _)| position).last(),
+            Some((1, 2))
+        );
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[rstest]
+    fn test_new_and_modify() {
+        let mut grid = Grid::new(2, 3, 0_u8);
+        grid[(2, 1)] = 7;
+        *grid.get_mut(0, 0).unwrap() = 1;
+        assert!(grid.get_mut(3, 0).is_none());
+        assert_eq!(grid.map(|&n| n * 2).to_string(), "20\n00\n014\n");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "off the 2x3 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = sample()[(2, 0)];
+    }
+}
@@ -0,0 +1,96 @@
+// Just enough HTML handling to pick pieces out of the puzzle site's pages, which are simple
+// and regular enough that a real parser would be overkill.
+
+/// The contents of every element starting with `open` and ending with `close`, in order.  If
+/// `open` is an unfinished tag, like `<article`, any attributes are skipped over.
+pub(crate) fn elements<'a>(
+    html: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)? + open.len();
+        let mut inner = &rest[start..];
+        if !open.ends_with('>') {
+            inner = &inner[inner.find('>')? + 1..];
+        }
+        let end = inner.find(close)?;
+        rest = &inner[end + close.len()..];
+        Some(&inner[..end])
+    })
+}
+
+/// The text with tags dropped and character references like `&lt;` decoded.  Whitespace is
+/// kept as it was.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    unescape(&text)
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|semi| {
+            let c = match &rest[1..semi] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, semi + 1))
+        });
+        // Anything unrecognised is left as it was
+        let (c, len) = decoded.unwrap_or(('&', 1));
+        out.push(c);
+        rest = &rest[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_elements() {
+        let html =
+            "<article class=\"day-desc\"><p>one</p></article>\n<article><p>two</p></article>";
+        let found: Vec<&str> = elements(html, "<article", "</article>").collect();
+        assert_eq!(found, ["<p>one</p>", "<p>two</p>"]);
+        let found: Vec<&str> = elements(html, "<p>", "</p>").collect();
+        assert_eq!(found, ["one", "two"]);
+        assert_eq!(elements(html, "<pre>", "</pre>").count(), 0);
+    }
+
+    #[rstest]
+    #[case("<code><em>357</em></code>", "357")]
+    #[case("a &lt;b&gt; &amp;&amp; &#39;c&#x27;", "a <b> && 'c'")]
+    #[case("AT&T &nope;", "AT&T &nope;")]
+    #[case("..#\n<em>.#.</em>\n", "..#\n.#.\n")]
+    fn test_strip_tags(#[case] html: &str, #[case] want: &str) {
+        assert_eq!(strip_tags(html), want);
+    }
+}
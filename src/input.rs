@@ -0,0 +1,480 @@
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use std::str;
+
+use memmap2::Mmap;
+
+use crate::AocError;
+
+// Puzzle input is read into memory once, and everything else borrows from it rather than
+// allocating a String per line.
+
+enum Data {
+    Owned(String),
+    Mapped(Mmap),
+}
+
+/// The complete contents of a puzzle input file
+pub struct Input {
+    path: String,
+    data: Data,
+}
+
+impl Input {
+    /// Reads the whole file into memory
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be read, or `AocError::Parse` if it isn't UTF-8
+    pub fn read(path: &str) -> Result<Self, AocError> {
+        let file = File::open(path).map_err(|source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        Self::from_reader(path, file)
+    }
+
+    /// Reads everything from `reader`, e.g. stdin.  `path` is only used when reporting errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if reading fails, or `AocError::Parse` if the data isn't UTF-8
+    pub fn from_reader<R: Read>(path: &str, mut reader: R) -> Result<Self, AocError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|source| AocError::Io {
+                path: path.to_owned(),
+                source,
+            })?;
+        match String::from_utf8(bytes) {
+            Ok(text) => Ok(Self::from_string(path, text)),
+            Err(e) => Err(utf8_error(path, e.as_bytes(), e.utf8_error())),
+        }
+    }
+
+    /// Memory maps the file instead of reading it.  The file must not be modified while the
+    /// `Input` is alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be mapped, or `AocError::Parse` if it isn't UTF-8
+    pub fn map(path: &str) -> Result<Self, AocError> {
+        let io_error = |source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        };
+        let file = File::open(path).map_err(io_error)?;
+        // Zero length maps aren't portable, and there's nothing to gain from one anyway
+        if file.metadata().map_err(io_error)?.len() == 0 {
+            return Ok(Self::from_string(path, String::new()));
+        }
+        // SAFETY: The map is read only, and we document that the file mustn't change under us.
+        let mmap = unsafe { Mmap::map(&file) }.map_err(io_error)?;
+        if let Err(e) = str::from_utf8(&mmap) {
+            return Err(utf8_error(path, &mmap, e));
+        }
+        Ok(Self {
+            path: path.to_owned(),
+            data: Data::Mapped(mmap),
+        })
+    }
+
+    /// Wraps text that has already been read, e.g. from stdin.  `path` is only used when
+    /// reporting errors.
+    pub fn from_string<T: Into<String>>(path: &str, text: T) -> Self {
+        Self {
+            path: path.to_owned(),
+            data: Data::Owned(text.into()),
+        }
+    }
+
+    /// Where the input came from
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn text(&self) -> &str {
+        match &self.data {
+            Data::Owned(text) => text,
+            // SAFETY: Validated as UTF-8 when the map was created, and the map is read only.
+            Data::Mapped(mmap) => unsafe { str::from_utf8_unchecked(mmap) },
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.text().as_bytes()
+    }
+
+    pub fn lines(&self) -> str::Lines<'_> {
+        self.text().lines()
+    }
+
+    /// Lines paired with their 1 based line numbers, for error reporting
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines().enumerate().map(|(idx, line)| (idx + 1, line))
+    }
+
+    /// The first line, or an error pointing at the start of the file if it's empty
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Parse` if the input is empty
+    pub fn first_line(&self) -> Result<&str, AocError> {
+        self.lines()
+            .next()
+            .ok_or_else(|| AocError::parse(&self.path, 1, 1, "", "Input is empty"))
+    }
+
+    /// Blocks of lines separated by one or more blank lines
+    pub fn paragraphs(&self) -> Paragraphs<'_> {
+        Paragraphs {
+            remaining: self.text(),
+            line: 1,
+        }
+    }
+
+    /// The input viewed as a grid of bytes, one row per line
+    pub fn grid(&self) -> ByteGrid<'_> {
+        ByteGrid::new(self.lines().map(str::as_bytes).collect())
+    }
+}
+
+/// Where a day's puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(String),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the input for a day.  An explicit path wins, then the `AOC_DAYN_INPUT` environment
+    /// variable, then `dayN.txt` inside `AOC_INPUT_DIR`, and finally `./data/dayN.txt`.  A path
+    /// of `-` means stdin.
+    pub fn resolve(day: u8, path: Option<&str>) -> Self {
+        Self::resolve_with(day, path, |name| env::var(name).ok())
+    }
+
+    /// As `resolve`, but looking up environment variables with `var`
+    pub fn resolve_with<F>(day: u8, path: Option<&str>, var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        // Treat empty variables as unset, so they can be cleared with `AOC_INPUT_DIR= ...`
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        if let Some(path) = path {
+            Self::from_path(path)
+        } else if let Some(path) = var(&format!("AOC_DAY{day}_INPUT")) {
+            Self::from_path(&path)
+        } else if let Some(dir) = var("AOC_INPUT_DIR") {
+            let path = Path::new(&dir).join(format!("day{day}.txt"));
+            Self::File(path.to_string_lossy().into_owned())
+        } else {
+            Self::File(format!("./data/day{day}.txt"))
+        }
+    }
+
+    fn from_path(path: &str) -> Self {
+        if path == "-" {
+            Self::Stdin
+        } else {
+            Self::File(path.to_owned())
+        }
+    }
+
+    /// Reads the input, memory mapping files if asked to.  Stdin is always read.
+    ///
+    /// # Errors
+    ///
+    /// As `Input::read` and `Input::map`
+    pub fn load(&self, mmap: bool) -> Result<Input, AocError> {
+        match self {
+            Self::File(path) if mmap => Input::map(path),
+            Self::File(path) => Input::read(path),
+            Self::Stdin => Input::from_reader(&self.to_string(), io::stdin().lock()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{path}"),
+            Self::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+fn utf8_error(path: &str, bytes: &[u8], e: str::Utf8Error) -> AocError {
+    let valid = &bytes[..e.valid_up_to()];
+    let line_start = valid
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |idx| idx + 1);
+    let line_end = bytes[line_start..]
+        .iter()
+        .position(|b| *b == b'\n')
+        .map_or(bytes.len(), |idx| line_start + idx);
+    // Only the part before the bad byte is known to be valid
+    let before = String::from_utf8_lossy(&valid[line_start..]);
+    AocError::parse(
+        path,
+        valid.iter().filter(|b| **b == b'\n').count() + 1,
+        before.chars().count() + 1,
+        &String::from_utf8_lossy(&bytes[line_start..line_end]),
+        "Input is not valid UTF-8",
+    )
+}
+
+/// A block of consecutive non-blank lines
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// 1 based line number of the first line in the block
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// Lines paired with their 1 based line numbers within the whole input
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + use<'a> {
+        let first_line = self.first_line;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(idx, line)| (first_line + idx, line))
+    }
+}
+
+/// Iterator over the blank line separated blocks of an input
+pub struct Paragraphs<'a> {
+    remaining: &'a str,
+    line: usize,
+}
+
+impl<'a> Iterator for Paragraphs<'a> {
+    type Item = Block<'a>;
+
+    fn next(&mut self) -> Option<Block<'a>> {
+        // Skip leading blank lines
+        loop {
+            let (line, rest) = split_first_line(self.remaining)?;
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.remaining = rest;
+            self.line += 1;
+        }
+        let first_line = self.line;
+        let start = self.remaining;
+        let mut length = 0;
+        while let Some((line, rest)) = split_first_line(self.remaining) {
+            if line.trim().is_empty() {
+                break;
+            }
+            length += self.remaining.len() - rest.len();
+            self.remaining = rest;
+            self.line += 1;
+        }
+        Some(Block {
+            first_line,
+            text: &start[..length],
+        })
+    }
+}
+
+// Splits off the first line, keeping its line ending so the lengths add up
+fn split_first_line(text: &str) -> Option<(&str, &str)> {
+    if text.is_empty() {
+        return None;
+    }
+    Some(match text.find('\n') {
+        Some(idx) => text.split_at(idx + 1),
+        None => (text, ""),
+    })
+}
+
+/// A grid of bytes borrowed from the input.  Rows may be ragged, in which case the grid is as
+/// wide as its longest row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteGrid<'a> {
+    rows: Vec<&'a [u8]>,
+    width: usize,
+}
+
+impl<'a> ByteGrid<'a> {
+    pub fn new(rows: Vec<&'a [u8]>) -> Self {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        Self { rows, width }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn row(&self, row: usize) -> Option<&'a [u8]> {
+        self.rows.get(row).copied()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        self.rows.iter().copied()
+    }
+
+    /// The byte at the given location, or `None` if it's beyond the end of the row
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        self.rows.get(row)?.get(col).copied()
+    }
+
+    /// The byte at the given location, treating short rows as padded with `padding`
+    pub fn get_or(&self, row: usize, col: usize, padding: u8) -> u8 {
+        self.get(row, col).unwrap_or(padding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_lines() {
+        let input = Input::from_string("test", "one\r\ntwo\n\nfour\n");
+        assert_eq!(
+            input.lines().collect::<Vec<_>>(),
+            ["one", "two", "", "four"]
+        );
+        assert_eq!(input.numbered_lines().nth(3), Some((4, "four")));
+        assert_eq!(input.first_line().unwrap(), "one");
+    }
+
+    #[rstest]
+    fn test_empty_input() {
+        let input = Input::from_string("test", "");
+        assert_eq!(input.lines().count(), 0);
+        assert_eq!(input.paragraphs().count(), 0);
+        assert!(matches!(
+            input.first_line(),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+    }
+
+    #[rstest]
+    #[case("a\nb\n\nc\n", vec![(1, "a\nb\n"), (4, "c\n")])]
+    #[case("\n\na\n\n\n\nb", vec![(3, "a\n"), (7, "b")])]
+    #[case("a\r\n\r\nb\r\n", vec![(1, "a\r\n"), (3, "b\r\n")])]
+    #[case("a\n  \nb\n", vec![(1, "a\n"), (3, "b\n")])] // whitespace only lines count as blank
+    fn test_paragraphs(#[case] text: &str, #[case] want: Vec<(usize, &str)>) {
+        let input = Input::from_string("test", text);
+        let got: Vec<(usize, &str)> = input
+            .paragraphs()
+            .map(|block| (block.first_line, block.text))
+            .collect();
+        assert_eq!(got, want);
+    }
+
+    #[rstest]
+    fn test_block_numbered_lines() {
+        let input = Input::from_string("test", "a\n\nb\nc\n");
+        let block = input.paragraphs().nth(1).unwrap();
+        assert_eq!(
+            block.numbered_lines().collect::<Vec<_>>(),
+            [(3, "b"), (4, "c")]
+        );
+    }
+
+    #[rstest]
+    fn test_grid() {
+        let input = Input::from_string("test", "abc\nd\nef\n");
+        let grid = input.grid();
+        assert_eq!((grid.height(), grid.width()), (3, 3));
+        assert_eq!(grid.get(0, 2), Some(b'c'));
+        assert_eq!(grid.get(1, 2), None);
+        assert_eq!(grid.get_or(1, 2, b' '), b' ');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.row(2), Some(&b"ef"[..]));
+    }
+
+    #[rstest]
+    fn test_read_and_map_agree() {
+        let read = Input::read("./data/day5_test").unwrap();
+        let mapped = Input::map("./data/day5_test").unwrap();
+        assert_eq!(read.text(), mapped.text());
+        assert_eq!(mapped.path(), "./data/day5_test");
+    }
+
+    #[rstest]
+    fn test_missing_file() {
+        for result in [
+            Input::read("./data/no_such_file"),
+            Input::map("./data/no_such_file"),
+        ] {
+            let err = result.err().unwrap();
+            assert!(matches!(err, AocError::Io { .. }));
+            assert!(
+                err.to_string()
+                    .starts_with("Unable to read ./data/no_such_file")
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_from_reader() {
+        let input = Input::from_reader("<stdin>", &b"a\nb\n"[..]).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(input.path(), "<stdin>");
+        assert!(matches!(
+            Input::from_reader("<stdin>", &b"\xff"[..]),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(Some("mine.txt"), &[("AOC_DAY3_INPUT", "env.txt")], InputSource::File("mine.txt".to_owned()))]
+    #[case(Some("-"), &[], InputSource::Stdin)]
+    #[case(None, &[("AOC_DAY3_INPUT", "env.txt"), ("AOC_INPUT_DIR", "/inputs")], InputSource::File("env.txt".to_owned()))]
+    #[case(None, &[("AOC_DAY3_INPUT", "-")], InputSource::Stdin)]
+    #[case(None, &[("AOC_DAY4_INPUT", "other_day.txt"), ("AOC_INPUT_DIR", "/inputs")], InputSource::File("/inputs/day3.txt".to_owned()))]
+    #[case(None, &[("AOC_DAY3_INPUT", ""), ("AOC_INPUT_DIR", "")], InputSource::File("./data/day3.txt".to_owned()))]
+    #[case(None, &[], InputSource::File("./data/day3.txt".to_owned()))]
+    fn test_resolve(
+        #[case] path: Option<&str>,
+        #[case] vars: &[(&str, &str)],
+        #[case] want: InputSource,
+    ) {
+        let env = |name: &str| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        };
+        assert_eq!(InputSource::resolve_with(3, path, env), want);
+    }
+
+    #[rstest]
+    fn test_invalid_utf8() {
+        let path = std::env::temp_dir().join(format!("aoc_invalid_utf8_{}", std::process::id()));
+        std::fs::write(&path, b"fine\nab\xffcd\n").unwrap();
+        let path = path.to_str().unwrap();
+        for result in [Input::read(path), Input::map(path)] {
+            match result {
+                Err(AocError::Parse {
+                    line, column, text, ..
+                }) => {
+                    assert_eq!((line, column), (2, 3));
+                    assert_eq!(text, "ab\u{FFFD}cd");
+                }
+                _ => panic!("Expected a parse error"),
+            }
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use ahash::AHashMap;
+
+/// Hands out dense ids, 0, 1, 2, ..., for keys in the order they're first seen, so the rest of
+/// a solution can work with `Vec`s indexed by id instead of hashing keys over and over.
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    ids: AHashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K> Default for Interner<K> {
+    fn default() -> Self {
+        Interner {
+            ids: AHashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Interner<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id for `key`, assigning the next one if it hasn't been seen before
+    pub fn intern(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    /// The id for `key`, if it has been interned
+    pub fn get<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+}
+
+impl<K> Interner<K> {
+    /// Panics if `id` wasn't handed out by this interner
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    /// Every key, indexed by id
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_intern() {
+        let mut interner = Interner::new();
+        assert!(interner.is_empty());
+        assert_eq!(interner.intern("you".to_owned()), 0);
+        assert_eq!(interner.intern("out".to_owned()), 1);
+        assert_eq!(interner.intern("you".to_owned()), 0);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("out"), Some(1));
+        assert_eq!(interner.get("svr"), None);
+        assert_eq!(interner.key(1), "out");
+        assert_eq!(interner.keys(), ["you", "out"]);
+    }
+}
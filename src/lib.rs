@@ -1,113 +1,412 @@
-use std::borrow::Cow;
-use std::fs;
-use std::time::{Duration, Instant};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+mod answers;
+mod bench;
+mod client;
+pub mod days;
+mod format;
+mod geometry;
+mod graph;
+mod grid;
+mod html;
+mod input;
+mod interner;
+mod log;
+mod parse;
+mod point;
+mod polyomino;
+mod range_set;
+mod report;
+mod samples;
+mod scaffold;
+mod search;
+mod submit;
+mod timing;
+mod union_find;
+mod watch;
+
+pub use answers::{Expected, InputKind, MANIFEST_PATH, Manifest, Verdict, verdict};
+pub use bench::{Baseline, Measurement, Stage, Stats, change_percent, measure};
+pub use client::{Client, ClientError, Config, DEFAULT_BASE_URL, Fetched, USER_AGENT, YEAR};
+pub use format::{
+    Align, Grouping, Table, format_bytes, format_decimal, format_duration, format_si,
+    print_with_thousands_separator,
+};
+pub use geometry::{Compressed, NotRectilinear, Polygon, RectilinearPolygon, Segment};
+pub use graph::{Cycle, Graph};
+pub use grid::{Grid, ORTHOGONAL, SURROUNDING};
+pub use input::{Block, ByteGrid, Input, InputSource, Paragraphs};
+pub use interner::Interner;
+pub use log::{Filter, Level, STATIC_MAX_LEVEL};
+#[doc(hidden)]
+pub use log::{enabled as __log_enabled, write as __log_write};
+pub use parse::{
+    parse_blocks, parse_bracketed, parse_key_value, parse_lines, parse_list, parse_number,
+    parse_range,
+};
+pub use point::{Point2, Point3};
+pub use polyomino::Polyomino;
+pub use range_set::{Integer, RangeSet};
+pub use report::{BUILD_PROFILE, Output, Record};
+pub use samples::{PartSample, SampleError, extract_samples, save_samples};
+pub use scaffold::{ScaffoldError, day_template, new_day};
+pub use search::{Route, astar, bfs, bfs_distances, dijkstra};
+pub use submit::{
+    ATTEMPTS_PATH, Attempt, AttemptLog, COOLDOWN, Outcome, Refusal, SubmitError, submit,
+};
+pub use timing::{Span, Timer, Timings, take_timings};
+pub use union_find::{KeyedUnionFind, UnionFind};
+pub use watch::{Answers, DEBOUNCE, DayWatcher, WatchError, describe_changes, read_records};
 
 // Common utilities for use across multiple days
 
-// from https://www.reddit.com/r/rust/comments/skmpnr/output_text_to_console_in_debug_mode_only/hvluai2/
-// This macro will insert a println at compile time, if the code is being compiled in debug mode
-// This over-optimisation avoids the overhead of thrown away log::debug entries etc.
-#[macro_export]
-macro_rules! debug_println {
-    ($($arg:tt)*) => (if ::std::cfg!(debug_assertions) { ::std::println!($($arg)*); })
-}
-
-/// # Panics
-///
-/// Will panic if it can't read the file
-#[inline]
-pub fn read_file(source: &str) -> Vec<String> {
-    // Reads in provided filename and returns a Vec<String>
-    fs::read_to_string(source)
-        .expect("Unable to read file")
-        .lines()
-        .map(std::string::ToString::to_string)
-        .collect()
-}
-
-/// Inspired by https://stackoverflow.com/questions/26998485/is-it-possible-to-print-a-number-formatted-with-thousand-separator-in-rust#comment136853740_67834588
-/// then made generic, and avoiding most of the allocations
-/// This method will print out numbers with thousands separators
-pub fn print_with_thousands_separator<T: std::fmt::Display>(val: T) -> String {
-    let s = val.to_string();
-    // Make a note if the number is negative for later use, stripping the prefix if it is.
-    let (is_neg, num_str) = if let Some(stripped) = s.strip_prefix('-') {
-        (true, stripped)
-    } else {
-        (false, s.as_str())
+/// Everything that can go wrong reading or parsing puzzle input
+#[derive(Debug)]
+pub enum AocError {
+    /// The file couldn't be read at all
+    Io { path: String, source: io::Error },
+    /// The file was read, but its contents didn't make sense
+    Parse {
+        path: String,
+        /// 1 based line number
+        line: usize,
+        /// 1 based column, counted in characters
+        column: usize,
+        /// The full text of the offending line
+        text: String,
+        reason: String,
+    },
+}
+
+impl AocError {
+    pub fn parse<T: Into<String>>(
+        path: &str,
+        line: usize,
+        column: usize,
+        text: &str,
+        reason: T,
+    ) -> Self {
+        AocError::Parse {
+            path: path.to_owned(),
+            line,
+            column,
+            text: text.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "Unable to read {path}: {source}"),
+            AocError::Parse {
+                path,
+                line,
+                column,
+                text,
+                reason,
+            } => {
+                // Mimic rustc, pointing a caret at the offending column
+                writeln!(f, "{path}:{line}:{column}: {reason}")?;
+                writeln!(f, "    {text}")?;
+                write!(f, "    {:>width$}", "^", width = *column)
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Parse { .. } => None,
+        }
+    }
+}
+
+/// A problem found while parsing a single line, before we know which file or line it came from.
+/// Attach that with `in_file`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    /// 1 based column, counted in characters
+    pub column: usize,
+    pub reason: String,
+}
+
+impl LineError {
+    pub fn new<T: Into<String>>(column: usize, reason: T) -> Self {
+        Self {
+            column,
+            reason: reason.into(),
+        }
+    }
+
+    /// Points the error at `part`, which must be a slice of `line`
+    pub fn at<T: Into<String>>(line: &str, part: &str, reason: T) -> Self {
+        Self::new(column_of(line, part), reason)
+    }
+
+    pub fn in_file(self, path: &str, line: usize, text: &str) -> AocError {
+        AocError::parse(path, line, self.column, text, self.reason)
+    }
+}
+
+/// 1 based column that `part` starts at within `line`.  `part` must be a slice of `line`, as
+/// handed out by `split`, `trim` and friends.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    debug_assert!(offset <= line.len(), "{part:?} isn't part of {line:?}");
+    line.get(..offset)
+        .map_or(1, |before| before.chars().count() + 1)
+}
+
+/// Which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "One"),
+            Part::Two => write!(f, "Two"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle, with its concrete type erased so that every day can be
+/// handled the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Solved(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Solved(answer) => write!(f, "{answer}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// Placeholder answer for parts that haven't been solved yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+/// Anything that a part of a day can return as its answer
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+macro_rules! impl_into_answer {
+    ($($answer:ty),*) => {
+        $(
+            impl IntoAnswer for $answer {
+                fn into_answer(self) -> Answer {
+                    Answer::Solved(self.to_string())
+                }
+            }
+        )*
     };
+}
 
-    let mut result = String::with_capacity(s.len() + (s.len() / 3));
+impl_into_answer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String, &str
+);
 
-    // Put the negative symbol back in
-    if is_neg {
-        result.push('-');
+/// For parts that can find there's no answer, which is reported the same as an unsolved part so
+/// it never gets submitted
+impl<T: IntoAnswer> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Answer {
+        self.map_or(Answer::Unsolved, IntoAnswer::into_answer)
     }
+}
 
-    let offset = num_str.len() % 3;
-    if offset > 0 {
-        result.push_str(&num_str[..offset]);
+impl IntoAnswer for Unsolved {
+    fn into_answer(self) -> Answer {
+        Answer::Unsolved
     }
+}
 
-    for (i, c) in num_str[offset..].chars().enumerate() {
-        if i % 3 == 0 && (offset > 0 || i > 0) {
-            result.push(',');
+/// Implemented by each day.  Parsing is kept separate from solving so that both parts can share
+/// the parsed input, and so that the two can be driven (and timed) independently.
+pub trait Solution {
+    /// Day of the advent calendar the puzzle is from
+    const DAY: u8;
+    /// Parsed puzzle input, shared by both parts
+    type Input: 'static;
+    type PartOne: IntoAnswer;
+    type PartTwo: IntoAnswer;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError>;
+    /// Parses one of the puzzle's examples.  A few puzzles change their rules for the examples,
+    /// like how many steps to take, and override this to say so.
+    fn parse_sample(input: &Input) -> Result<Self::Input, AocError> {
+        Self::parse(input)
+    }
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// A day's parsed input, ready to be solved without knowing which day it came from
+pub trait Puzzle {
+    fn solve(&self, part: Part) -> Answer;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Puzzle for Parsed<S> {
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => S::part_one(&self.0).into_answer(),
+            Part::Two => S::part_two(&self.0).into_answer(),
         }
-        result.push(c);
     }
+}
+
+fn parse_erased<S: Solution + 'static>(input: &Input) -> Result<Box<dyn Puzzle>, AocError> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
 
-    result
+fn parse_sample_erased<S: Solution + 'static>(input: &Input) -> Result<Box<dyn Puzzle>, AocError> {
+    Ok(Box::new(Parsed::<S>(S::parse_sample(input)?)))
 }
 
-pub struct Timer {
-    start_time: Instant,
-    name: Cow<'static, str>,
+/// An entry in the day registry
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(&Input) -> Result<Box<dyn Puzzle>, AocError>,
+    parse_sample: fn(&Input) -> Result<Box<dyn Puzzle>, AocError>,
 }
 
-impl Drop for Timer {
-    fn drop(&mut self) {
-        self.elapsed();
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Day").field("day", &self.day).finish()
     }
 }
 
-impl Timer {
-    pub fn start<T>(name: T) -> Self
-    where
-        T: Into<Cow<'static, str>>,
-    {
-        let name: Cow<'static, str> = name.into();
-        debug_println!("Creating timer called \"{}\"", name);
-        Timer {
-            start_time: Instant::now(),
-            name,
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Day {
+            day: S::DAY,
+            parse: parse_erased::<S>,
+            parse_sample: parse_sample_erased::<S>,
         }
     }
 
-    pub fn duration(&self) -> Duration {
-        self.start_time.elapsed()
+    pub fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, AocError> {
+        (self.parse)(input)
     }
 
-    pub fn elapsed(&self) {
-        println!("[{}] took {:?}", self.name, self.duration());
+    /// As `parse`, for one of the puzzle's examples
+    pub fn parse_sample(&self, input: &Input) -> Result<Box<dyn Puzzle>, AocError> {
+        (self.parse_sample)(input)
     }
 
-    pub fn secs_so_far(&self) -> u64 {
-        self.start_time.elapsed().as_secs()
+    /// Where the sample input for the given part is expected to be.  Some days use a different
+    /// example for part two, which lives in `dayN_2_test`.
+    pub fn sample_path(&self, part: Part) -> String {
+        if part == Part::Two {
+            let part_two = format!("./data/day{}_2_test", self.day);
+            if Path::new(&part_two).exists() {
+                return part_two;
+            }
+        }
+        format!("./data/day{}_test", self.day)
     }
 }
 
+/// Every implemented day, in order
+pub static DAYS: [Day; 12] = [
+    Day::of::<days::day1::Day1>(),
+    Day::of::<days::day2::Day2>(),
+    Day::of::<days::day3::Day3>(),
+    Day::of::<days::day4::Day4>(),
+    Day::of::<days::day5::Day5>(),
+    Day::of::<days::day6::Day6>(),
+    Day::of::<days::day7::Day7>(),
+    Day::of::<days::day8::Day8>(),
+    Day::of::<days::day9::Day9>(),
+    Day::of::<days::day10::Day10>(),
+    Day::of::<days::day11::Day11>(),
+    Day::of::<days::day12::Day12>(),
+];
+
+/// Look up a day in the registry by its number
+pub fn get_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
 
     #[rstest]
-    #[case(-1234567 as isize, "-1,234,567")]
-    #[case(9876543210 as u64, "9,876,543,210")]
-    #[case(1000 as i32, "1,000")]
-    #[case(255 as u8, "255")]
-    #[case(-128 as i16, "-128")] // Make sure we don't get "-,128"
-    fn test_thousands_separator<T: std::fmt::Display>(#[case] val: T, #[case] want: String) {
-        assert_eq!(print_with_thousands_separator(val), want);
+    fn test_registry_is_keyed_by_day() {
+        for (idx, day) in DAYS.iter().enumerate() {
+            assert_eq!(usize::from(day.day), idx + 1);
+            assert_eq!(get_day(day.day).unwrap().day, day.day);
+        }
+        assert!(get_day(0).is_none());
+        assert!(get_day(26).is_none());
+    }
+
+    #[rstest]
+    #[case(3, Part::One, "357")]
+    #[case(3, Part::Two, "3121910778619")]
+    #[case(7, Part::Two, "40")]
+    #[case(8, Part::One, "40")]
+    #[case(11, Part::Two, "2")]
+    fn test_registry_solves_sample(#[case] day: u8, #[case] part: Part, #[case] want: &str) {
+        let day = get_day(day).unwrap();
+        let input = Input::read(&day.sample_path(part)).unwrap();
+        let puzzle = day.parse_sample(&input).unwrap();
+        assert_eq!(puzzle.solve(part), Answer::Solved(want.to_string()));
+    }
+
+    #[rstest]
+    fn test_into_answer() {
+        assert_eq!(42usize.into_answer(), Answer::Solved("42".to_owned()));
+        assert_eq!((-3isize).into_answer(), Answer::Solved("-3".to_owned()));
+        assert_eq!(Some(7u64).into_answer(), Answer::Solved("7".to_owned()));
+        assert_eq!(None::<usize>.into_answer(), Answer::Unsolved);
+        assert_eq!(Unsolved.into_answer(), Answer::Unsolved);
+    }
+
+    #[rstest]
+    fn test_parse_error_display() {
+        let err = AocError::parse("./data/day1_test", 3, 2, "L1x", "invalid amount");
+        assert_eq!(
+            err.to_string(),
+            "./data/day1_test:3:2: invalid amount\n    L1x\n     ^"
+        );
+    }
+
+    #[rstest]
+    #[case("a,bc,d", 0, 1)]
+    #[case("a,bc,d", 2, 3)]
+    #[case("a,bc,d", 5, 6)]
+    #[case("é,x", 3, 3)] // columns count characters, not bytes
+    fn test_column_of(#[case] line: &str, #[case] offset: usize, #[case] want: usize) {
+        assert_eq!(column_of(line, &line[offset..]), want);
+    }
+
+    #[rstest]
+    #[case(7, Part::One, "./data/day7_test")]
+    #[case(7, Part::Two, "./data/day7_test")]
+    #[case(11, Part::One, "./data/day11_test")]
+    #[case(11, Part::Two, "./data/day11_2_test")]
+    fn test_sample_path(#[case] day: u8, #[case] part: Part, #[case] want: &str) {
+        assert_eq!(get_day(day).unwrap().sample_path(part), want);
     }
 }
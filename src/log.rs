@@ -0,0 +1,266 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::sync::{Mutex, OnceLock};
+
+/// How much detail a message carries, from least to most chatty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        })
+    }
+}
+
+/// The most detailed level compiled in.  Debug and trace messages vanish from release builds
+/// entirely unless the `trace` feature is enabled, so they cost nothing in hot loops.
+pub const STATIC_MAX_LEVEL: Level = if cfg!(any(debug_assertions, feature = "trace")) {
+    Level::Trace
+} else {
+    Level::Info
+};
+
+/// Which levels to show for which modules, parsed from a spec like `debug,day8=trace,days=off`.
+/// A bare level sets the default.  `name=level` applies to any module path containing `name`
+/// as a whole segment, or run of segments, with the longest matching name winning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    directives: Vec<(String, Option<Level>)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter {
+            default: Some(Level::Warn),
+            directives: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    /// Returns the filter along with any parts of the spec that made no sense
+    pub fn parse(spec: &str) -> (Self, Vec<String>) {
+        let mut filter = Filter::default();
+        let mut invalid = Vec::new();
+        let level = |name: &str| match name.trim() {
+            "off" => Some(None),
+            name => Level::parse(name).map(Some),
+        };
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                None => match level(directive) {
+                    Some(level) => filter.default = level,
+                    None => invalid.push(directive.to_owned()),
+                },
+                Some((name, value)) => match level(value) {
+                    Some(level) if !name.trim().is_empty() => {
+                        filter.directives.push((name.trim().to_owned(), level));
+                    }
+                    _ => invalid.push(directive.to_owned()),
+                },
+            }
+        }
+        (filter, invalid)
+    }
+
+    /// The most detailed level shown for messages from `target`, a module path
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        // Ignore the crate name, so `advent_of_code_2025::days::day8` is matched by `days::day8`
+        let target = target.split_once("::").map_or(target, |(_, rest)| rest);
+        let segments: Vec<&str> = target.split("::").collect();
+        self.directives
+            .iter()
+            .filter(|(name, _)| {
+                let wanted: Vec<&str> = name.split("::").collect();
+                segments
+                    .windows(wanted.len())
+                    .any(|window| window == wanted)
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        self.level_for(target).is_some_and(|max| level <= max)
+    }
+
+    /// The most detailed level any module can show, for a cheap first check
+    fn max_level(&self) -> Option<Level> {
+        self.directives
+            .iter()
+            .map(|(_, level)| *level)
+            .chain([self.default])
+            .max()
+            .flatten()
+    }
+}
+
+struct Logger {
+    filter: Filter,
+    max_level: Option<Level>,
+    out: Mutex<Box<dyn Write + Send>>,
+}
+
+/// Set up from `AOC_LOG` and `AOC_LOG_FILE` the first time anything is logged
+fn logger() -> &'static Logger {
+    static LOGGER: OnceLock<Logger> = OnceLock::new();
+    LOGGER.get_or_init(|| {
+        let (filter, invalid) = match std::env::var("AOC_LOG") {
+            Ok(spec) => Filter::parse(&spec),
+            Err(_) => (Filter::default(), Vec::new()),
+        };
+        for directive in invalid {
+            eprintln!("Ignoring {directive:?} in AOC_LOG, expected a level or name=level");
+        }
+        let out: Box<dyn Write + Send> = match std::env::var("AOC_LOG_FILE") {
+            Ok(path) => match OpenOptions::new().create(true).append(true).open(&path) {
+                Ok(file) => Box::new(io::LineWriter::new(file)),
+                Err(e) => {
+                    eprintln!("Unable to open {path} for logging, using stderr: {e}");
+                    Box::new(io::stderr())
+                }
+            },
+            Err(_) => Box::new(io::stderr()),
+        };
+        Logger {
+            max_level: filter.max_level(),
+            filter,
+            out: Mutex::new(out),
+        }
+    })
+}
+
+/// Whether a message at `level` from `target` would be written.  Used by the logging macros.
+#[doc(hidden)]
+pub fn enabled(level: Level, target: &str) -> bool {
+    let logger = logger();
+    logger.max_level.is_some_and(|max| level <= max) && logger.filter.enabled(level, target)
+}
+
+/// Write a message that has already passed `enabled`.  Used by the logging macros.
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    let target = target.split_once("::").map_or(target, |(_, rest)| rest);
+    // A poisoned lock only means another thread panicked mid-message, so carry on
+    let mut out = logger().out.lock().unwrap_or_else(|e| e.into_inner());
+    // Nowhere better to report a failure to log
+    let _ = writeln!(out, "[{level:5} {target}] {args}");
+}
+
+/// Log at a given level, filtered at runtime by `AOC_LOG` and compiled out entirely above
+/// `STATIC_MAX_LEVEL`.  The arguments aren't evaluated unless the message is shown.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level: $crate::Level = $level;
+        if level <= $crate::STATIC_MAX_LEVEL && $crate::__log_enabled(level, ::std::module_path!()) {
+            $crate::__log_write(level, ::std::module_path!(), ::std::format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => ($crate::log!($crate::Level::Error, $($arg)+));
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => ($crate::log!($crate::Level::Warn, $($arg)+));
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => ($crate::log!($crate::Level::Info, $($arg)+));
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => ($crate::log!($crate::Level::Debug, $($arg)+));
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => ($crate::log!($crate::Level::Trace, $($arg)+));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "advent_of_code_2025::days::day8", Some(Level::Warn))]
+    #[case("debug", "advent_of_code_2025::days::day8", Some(Level::Debug))]
+    #[case("off", "advent_of_code_2025::days::day8", None)]
+    #[case(
+        "debug,day8=trace",
+        "advent_of_code_2025::days::day8",
+        Some(Level::Trace)
+    )]
+    #[case(
+        "debug,day8=trace",
+        "advent_of_code_2025::days::day9",
+        Some(Level::Debug)
+    )]
+    #[case("days=info,days::day8=off", "advent_of_code_2025::days::day8", None)]
+    #[case(
+        "days=info,days::day8=off",
+        "advent_of_code_2025::days::day1",
+        Some(Level::Info)
+    )]
+    #[case("day1=trace", "advent_of_code_2025::days::day10", Some(Level::Warn))]
+    #[case("timing=DEBUG", "advent_of_code_2025::timing", Some(Level::Debug))]
+    // Later defaults win
+    #[case("trace,error", "advent_of_code_2025::input", Some(Level::Error))]
+    fn test_level_for(#[case] spec: &str, #[case] target: &str, #[case] want: Option<Level>) {
+        let (filter, invalid) = Filter::parse(spec);
+        assert!(invalid.is_empty());
+        assert_eq!(filter.level_for(target), want);
+    }
+
+    #[rstest]
+    fn test_invalid_directives() {
+        let (filter, invalid) = Filter::parse("loud, day8=shouty,=debug , info");
+        assert_eq!(invalid, ["loud", "day8=shouty", "=debug"]);
+        assert_eq!(filter.default, Some(Level::Info));
+        assert!(filter.directives.is_empty());
+    }
+
+    #[rstest]
+    fn test_enabled() {
+        let (filter, _) = Filter::parse("info,day8=trace");
+        assert!(filter.enabled(Level::Warn, "aoc::days::day1"));
+        assert!(!filter.enabled(Level::Debug, "aoc::days::day1"));
+        assert!(filter.enabled(Level::Trace, "aoc::days::day8"));
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+        assert_eq!(Filter::parse("off").0.max_level(), None);
+    }
+}
@@ -1,3 +1,814 @@
-fn main() {
-    println!("Hello, world!");
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use advent_of_code_2025::*;
+
+/// Runs the Advent of Code 2025 solutions
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every day
+    Run {
+        /// Day to run
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run every registered day
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Only run one part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Read the puzzle input from this file, or - for stdin.  Defaults to $AOC_DAYN_INPUT,
+        /// then dayN.txt in $AOC_INPUT_DIR, then ./data/dayN.txt
+        #[arg(long)]
+        input: Option<String>,
+        /// Use the sample input from ./data/dayN_test
+        #[arg(long, conflicts_with = "input")]
+        sample: bool,
+        /// Memory map the input rather than reading it
+        #[arg(long)]
+        mmap: bool,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Time parsing and solving over many runs, optionally comparing against a saved baseline
+    Bench {
+        /// Day to benchmark
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Benchmark every registered day
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Read the puzzle input from this file, or - for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Use the sample input from ./data/dayN_test
+        #[arg(long, conflicts_with = "input")]
+        sample: bool,
+        /// Measured runs of each stage
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Unmeasured runs of each stage before measuring
+        #[arg(long, default_value_t = 5)]
+        warmup: usize,
+        /// Write the results to this file, to compare later runs against
+        #[arg(long)]
+        save: Option<String>,
+        /// Compare against a file written by --save
+        #[arg(long)]
+        baseline: Option<String>,
+        /// Flag stages whose median got slower than the baseline by more than this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check every day's answers against the expected answers manifest
+    Verify {
+        /// Only verify this day
+        day: Option<u8>,
+        /// The expected answers
+        #[arg(long, default_value = MANIFEST_PATH)]
+        manifest: String,
+        /// Save answers that have no expected answer yet, hashing the ones for real inputs
+        #[arg(long)]
+        record: bool,
+    },
+    /// List the registered days
+    List,
+    /// Download a day's puzzle input, unless it's already been downloaded.  The session token
+    /// comes from $AOC_SESSION, or the config file at $AOC_CONFIG or ~/.config/aoc/config.toml
+    Fetch {
+        /// Day to download
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// Save the input here instead of where `run` looks for it
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Solve a part on the real input and submit the answer, unless earlier attempts show it's
+    /// wrong or it's too soon to try again
+    Submit {
+        /// Day to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// Part to submit, 1 or 2
+        #[arg(value_parser = parse_part)]
+        part: Part,
+        /// Read the puzzle input from this file, or - for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Where every attempt is recorded
+        #[arg(long, default_value = ATTEMPTS_PATH)]
+        log: String,
+        /// The expected answers, where a right answer gets recorded
+        #[arg(long, default_value = MANIFEST_PATH)]
+        manifest: String,
+    },
+    /// Save the examples from a puzzle page saved by the browser as sample inputs, and their
+    /// answers in the expected answers manifest
+    Samples {
+        /// Day the page is for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// The saved HTML page
+        page: String,
+        /// The expected answers
+        #[arg(long, default_value = MANIFEST_PATH)]
+        manifest: String,
+        /// Replace samples and answers that are already there
+        #[arg(long)]
+        force: bool,
+    },
+    /// Re-run a day on its samples and real input whenever its source or data changes, showing
+    /// how the answers compare with the last run and the expected answers
+    Watch {
+        /// Day to watch
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// The expected answers
+        #[arg(long, default_value = MANIFEST_PATH)]
+        manifest: String,
+    },
+    /// Start a new day from the template, with an empty sample file to paste into
+    NewDay {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Readable answers, followed by a timing summary
+    Text,
+    /// One JSON object per part
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("part must be 1 or 2, not {value:?}")),
+    }
+}
+
+fn run_day<W: Write>(
+    day: &Day,
+    parts: &[Part],
+    input: Option<&str>,
+    sample: bool,
+    mmap: bool,
+    output: &mut Output<W>,
+) -> Result<(), AocError> {
+    let _t = Timer::start(format!("Day {}", day.day));
+    // Both parts normally share the same parsed input, but samples can differ between parts.
+    let mut parsed: Option<(InputSource, Duration, Box<dyn Puzzle>)> = None;
+    for &part in parts {
+        let source = if sample {
+            InputSource::File(day.sample_path(part))
+        } else {
+            InputSource::resolve(day.day, input)
+        };
+        if parsed
+            .as_ref()
+            .is_none_or(|(parsed_from, _, _)| *parsed_from != source)
+        {
+            let start = Instant::now();
+            let input = source.load(mmap)?;
+            let puzzle = if sample {
+                day.parse_sample(&input)?
+            } else {
+                day.parse(&input)?
+            };
+            parsed = Some((source, start.elapsed(), puzzle));
+        }
+        // Safe, it was populated just above
+        let (source, parse_time, puzzle) = parsed.as_ref().unwrap();
+        let start = Instant::now();
+        let answer = puzzle.solve(part);
+        let record = Record::new(day.day, part, &answer, *parse_time, start.elapsed(), source);
+        output.record(&record).map_err(|source| AocError::Io {
+            path: "<stdout>".to_owned(),
+            source,
+        })?;
+    }
+    Ok(())
+}
+
+/// The days to work on, or `None` after reporting a day that doesn't exist
+fn select_days(day: Option<u8>, all: bool) -> Option<Vec<&'static Day>> {
+    if all {
+        return Some(DAYS.iter().collect());
+    }
+    // clap guarantees a day when --all is missing
+    let day = day.unwrap();
+    match get_day(day) {
+        Some(found) => Some(vec![found]),
+        None => {
+            eprintln!("Day {day} has not been implemented");
+            None
+        }
+    }
+}
+
+fn bench_day(
+    day: &Day,
+    input: Option<&str>,
+    sample: bool,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Vec<Measurement>, AocError> {
+    let mut measurements = Vec::new();
+    let parse = |input: &Input| {
+        if sample {
+            day.parse_sample(input)
+        } else {
+            day.parse(input)
+        }
+    };
+    let mut parsed: Option<(InputSource, Box<dyn Puzzle>)> = None;
+    for part in [Part::One, Part::Two] {
+        let source = if sample {
+            InputSource::File(day.sample_path(part))
+        } else {
+            InputSource::resolve(day.day, input)
+        };
+        if parsed
+            .as_ref()
+            .is_none_or(|(parsed_from, _)| *parsed_from != source)
+        {
+            let text = source.load(false)?;
+            let puzzle = parse(&text)?;
+            // Part two only gets a parse of its own when its input is different
+            let stage = if parsed.is_none() {
+                Stage::Parse
+            } else {
+                Stage::ParsePartTwo
+            };
+            let stats = measure(warmup, iterations, || parse(&text));
+            measurements.push(Measurement {
+                day: day.day,
+                stage,
+                stats,
+            });
+            parsed = Some((source, puzzle));
+        }
+        // Safe, it was populated just above
+        let (_, puzzle) = parsed.as_ref().unwrap();
+        let stats = measure(warmup, iterations, || puzzle.solve(part));
+        measurements.push(Measurement {
+            day: day.day,
+            stage: part.into(),
+            stats,
+        });
+    }
+    Ok(measurements)
+}
+
+/// Works out a part's answer and sends it, recording a right answer in the manifest
+fn submit_day(
+    day: &Day,
+    part: Part,
+    input: Option<&str>,
+    log: &str,
+    manifest: &str,
+) -> Result<Outcome, Box<dyn std::error::Error>> {
+    let puzzle = day.parse(&InputSource::resolve(day.day, input).load(false)?)?;
+    let Answer::Solved(answer) = puzzle.solve(part) else {
+        return Err(format!("Day {} part {part} isn't solved yet", day.day).into());
+    };
+    take_timings();
+    println!("Day {} Part {part}: submitting {answer}", day.day);
+    let mut log = AttemptLog::load(log)?;
+    let client = Client::new(Config::load()?);
+    let outcome = submit(
+        &client,
+        &mut log,
+        day.day,
+        part,
+        &answer,
+        std::time::SystemTime::now(),
+    )?;
+    if outcome == Outcome::Correct {
+        let mut manifest = Manifest::load(manifest)?;
+        if manifest.expected(day.day, InputKind::Real, part).is_none() {
+            manifest.set(day.day, InputKind::Real, part, &Expected::hashed(&answer));
+            manifest.save()?;
+        }
+    }
+    Ok(outcome)
+}
+
+/// Builds the current source and runs it on the day's inputs, so that edits to the day are
+/// picked up.  Inputs that don't exist are skipped, and so are parts that fail, whose errors
+/// are left for cargo and the run to report.
+fn watch_run(day: &Day) -> Answers {
+    let mut answers = Answers::new();
+    for kind in [InputKind::Sample, InputKind::Real] {
+        // Only the parts with an input, as a day can have a part two sample and no other
+        let parts: Vec<Part> = [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| {
+                let source = match kind {
+                    InputKind::Sample => InputSource::File(day.sample_path(part)),
+                    InputKind::Real => InputSource::resolve(day.day, None),
+                };
+                match &source {
+                    InputSource::File(path) => std::path::Path::new(path).exists(),
+                    _ => true,
+                }
+            })
+            .collect();
+        if parts.is_empty() {
+            continue;
+        }
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut command = std::process::Command::new(cargo);
+        command.args(["run", "--quiet", "--bin", env!("CARGO_BIN_NAME")]);
+        if BUILD_PROFILE == "release" {
+            command.arg("--release");
+        }
+        command
+            .args(["--", "run", &day.day.to_string(), "--format", "json"])
+            .stdin(std::process::Stdio::null())
+            .stderr(std::process::Stdio::inherit());
+        if kind == InputKind::Sample {
+            command.arg("--sample");
+        }
+        if let [part] = parts[..] {
+            command.args(["--part", if part == Part::One { "1" } else { "2" }]);
+        }
+        match command.output() {
+            Ok(output) => match read_records(kind, &String::from_utf8_lossy(&output.stdout)) {
+                Ok(found) => answers.extend(found),
+                Err(e) => eprintln!("Unable to read the {kind} answers: {e}"),
+            },
+            Err(e) => {
+                eprintln!("Unable to run cargo: {e}");
+                break;
+            }
+        }
+    }
+    answers
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    pass: usize,
+    fail: usize,
+    missing: usize,
+}
+
+fn verify_day(day: &Day, manifest: &mut Manifest, record: bool, tally: &mut Tally) {
+    for kind in [InputKind::Sample, InputKind::Real] {
+        let mut parsed: Option<(InputSource, Box<dyn Puzzle>)> = None;
+        for part in [Part::One, Part::Two] {
+            let source = match kind {
+                InputKind::Sample => InputSource::File(day.sample_path(part)),
+                InputKind::Real => InputSource::resolve(day.day, None),
+            };
+            let label = format!("Day {:>2}  {kind:6}  Part {part:3}", day.day);
+            let expected = manifest.expected(day.day, kind, part);
+            if let InputSource::File(path) = &source
+                && !std::path::Path::new(path).exists()
+            {
+                // Not having an input isn't a failure, but do say if there was an answer for it
+                if expected.is_some() {
+                    println!("{label}  {:7}  no input at {path}", Verdict::Missing);
+                    tally.missing += 1;
+                }
+                continue;
+            }
+            if parsed
+                .as_ref()
+                .is_none_or(|(parsed_from, _)| *parsed_from != source)
+            {
+                let parse = |input: Input| match kind {
+                    InputKind::Sample => day.parse_sample(&input),
+                    InputKind::Real => day.parse(&input),
+                };
+                match source.load(false).and_then(parse) {
+                    Ok(puzzle) => parsed = Some((source, puzzle)),
+                    Err(e) => {
+                        println!("{label}  {:7}  {e}", Verdict::Fail);
+                        tally.fail += 1;
+                        parsed = None;
+                        continue;
+                    }
+                }
+            }
+            // Safe, it was populated just above
+            let (_, puzzle) = parsed.as_ref().unwrap();
+            let answer = puzzle.solve(part);
+            let verdict = verdict(expected.as_ref(), &answer);
+            let detail = match (verdict, &expected, &answer) {
+                (Verdict::Fail, Some(Expected::Clear(expected)), _) => {
+                    format!("got {answer}, expected {expected}")
+                }
+                (Verdict::Fail, _, _) => format!("got {answer}, which doesn't match the hash"),
+                (Verdict::Missing, None, Answer::Solved(solved)) if record => {
+                    let expected = match kind {
+                        InputKind::Sample => Expected::Clear(solved.clone()),
+                        InputKind::Real => Expected::hashed(solved),
+                    };
+                    manifest.set(day.day, kind, part, &expected);
+                    format!("got {answer}, recorded")
+                }
+                _ => format!("got {answer}"),
+            };
+            println!("{label}  {verdict:7}  {detail}");
+            match verdict {
+                Verdict::Pass => tally.pass += 1,
+                Verdict::Fail => tally.fail += 1,
+                Verdict::Missing => tally.missing += 1,
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            sample,
+            mmap,
+            format,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![Part::One, Part::Two],
+            };
+            let Some(days) = select_days(day, all) else {
+                return ExitCode::FAILURE;
+            };
+            // Keep going after a bad input, so --all reports on every day
+            let mut output = match format {
+                Format::Text => Output::Text(io::stdout()),
+                Format::Json => Output::Json(io::stdout()),
+                Format::Csv => Output::csv(io::stdout()),
+            };
+            let mut failed = false;
+            for day in days {
+                let result = run_day(day, &parts, input.as_deref(), sample, mmap, &mut output);
+                if let Err(e) = result {
+                    eprintln!("Day {} failed: {e}", day.day);
+                    failed = true;
+                }
+            }
+            // Keep stdout clean for anything reading the structured formats
+            let timings = take_timings();
+            if output.is_text() && !timings.is_empty() {
+                print!("\nTimings:\n{timings}");
+            }
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            day,
+            all,
+            input,
+            sample,
+            iterations,
+            warmup,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let Some(days) = select_days(day, all) else {
+                return ExitCode::FAILURE;
+            };
+            let baseline = match baseline.as_deref().map(Baseline::load).transpose() {
+                Ok(baseline) => baseline,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if let Some(baseline) = &baseline
+                && baseline.profile != BUILD_PROFILE
+            {
+                eprintln!(
+                    "Warning: comparing a {BUILD_PROFILE} build against a {} baseline",
+                    baseline.profile
+                );
+            }
+            let mut failed = false;
+            let mut regressed = false;
+            let mut measurements = Vec::new();
+            for day in days {
+                match bench_day(day, input.as_deref(), sample, warmup, iterations) {
+                    Ok(day_measurements) => measurements.extend(day_measurements),
+                    Err(e) => {
+                        eprintln!("Day {} failed: {e}", day.day);
+                        failed = true;
+                        continue;
+                    }
+                }
+                // The parsers' own timers would otherwise pile up over every iteration
+                take_timings();
+            }
+            let mut table = Table::new([
+                Align::Right,
+                Align::Left,
+                Align::Right,
+                Align::Right,
+                Align::Right,
+                Align::Right,
+                Align::Right,
+                Align::Left,
+            ])
+            .header([
+                "Day", "Stage", "Min", "Median", "P95", "Stddev", "Change", "Flag",
+            ]);
+            for Measurement { day, stage, stats } in &measurements {
+                let ns = |nanos: u64| format_duration(Duration::from_nanos(nanos));
+                let change = baseline
+                    .as_ref()
+                    .and_then(|baseline| baseline.find(*day, *stage))
+                    .map(|before| change_percent(stats, before));
+                let change_text = change.map_or("-".to_owned(), |change| format!("{change:+.1}%"));
+                let flag = if change.is_some_and(|change| change > threshold) {
+                    regressed = true;
+                    "REGRESSION"
+                } else {
+                    ""
+                };
+                table.push([
+                    day.to_string(),
+                    stage.to_string(),
+                    ns(stats.min_ns),
+                    ns(stats.median_ns),
+                    ns(stats.p95_ns),
+                    ns(stats.stddev_ns.round() as u64),
+                    change_text,
+                    flag.to_owned(),
+                ]);
+            }
+            print!("{table}");
+            if let Some(path) = save
+                && let Err(e) = Baseline::new(measurements).save(&path)
+            {
+                eprintln!("{e}");
+                failed = true;
+            }
+            if failed || regressed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Verify {
+            day,
+            manifest,
+            record,
+        } => {
+            let Some(days) = select_days(day, day.is_none()) else {
+                return ExitCode::FAILURE;
+            };
+            let mut manifest = match Manifest::load(&manifest) {
+                Ok(manifest) => manifest,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut tally = Tally::default();
+            for day in days {
+                verify_day(day, &mut manifest, record, &mut tally);
+            }
+            take_timings();
+            println!(
+                "\n{} passed, {} failed, {} missing",
+                tally.pass, tally.fail, tally.missing
+            );
+            if record && let Err(e) = manifest.save() {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+            if tally.fail > 0 {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::List => {
+            for day in &DAYS {
+                let input = InputSource::resolve(day.day, None);
+                let status = match &input {
+                    InputSource::File(path) if !std::path::Path::new(path).exists() => "missing",
+                    _ => "present",
+                };
+                println!("Day {:>2}\tinput {input} ({status})", day.day);
+            }
+        }
+        Command::Fetch { day, output } => {
+            let InputSource::File(path) = InputSource::resolve(day, output.as_deref()) else {
+                eprintln!("Can't save an input to stdin");
+                return ExitCode::FAILURE;
+            };
+            let result = Config::load()
+                .map_err(ClientError::from)
+                .and_then(|config| Client::new(config).fetch_input(day, &path));
+            match result {
+                Ok(Fetched::Cached) => println!("{path} already exists, not downloading it again"),
+                Ok(Fetched::Downloaded(bytes)) => {
+                    println!("Saved {} of input to {path}", format_bytes(bytes as u64));
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            input,
+            log,
+            manifest,
+        } => {
+            let Some(days) = select_days(Some(day), false) else {
+                return ExitCode::FAILURE;
+            };
+            match submit_day(days[0], part, input.as_deref(), &log, &manifest) {
+                Ok(outcome) => {
+                    println!("{outcome}");
+                    if outcome != Outcome::Correct {
+                        return ExitCode::FAILURE;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Samples {
+            day,
+            page,
+            manifest,
+            force,
+        } => {
+            let html = match std::fs::read_to_string(&page) {
+                Ok(html) => html,
+                Err(e) => {
+                    eprintln!("Unable to read {page}: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut manifest = match Manifest::load(&manifest) {
+                Ok(manifest) => manifest,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let samples = extract_samples(&html);
+            let data = std::path::Path::new("./data");
+            match save_samples(day, &samples, data, &mut manifest, force) {
+                Ok(changes) => {
+                    for change in changes {
+                        println!("{change}");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+            if let Err(e) = manifest.save() {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Watch { day, manifest } => {
+            let Some(found) = get_day(day) else {
+                eprintln!("Day {day} has not been implemented");
+                return ExitCode::FAILURE;
+            };
+            let mut watcher = match DayWatcher::new(day, std::path::Path::new(".")) {
+                Ok(watcher) => watcher,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut previous = None;
+            loop {
+                let answers = watch_run(found);
+                match Manifest::load(&manifest) {
+                    Ok(manifest) => {
+                        for line in describe_changes(day, previous.as_ref(), &answers, &manifest) {
+                            println!("{line}");
+                        }
+                    }
+                    Err(e) => eprintln!("{e}"),
+                }
+                previous = Some(answers);
+                println!("Watching day {day} for changes");
+                match watcher.wait() {
+                    Ok(changed) => {
+                        let changed: Vec<String> = changed
+                            .iter()
+                            .map(|path| path.display().to_string())
+                            .collect();
+                        println!("\n{} changed", changed.join(", "));
+                    }
+                    Err(e) => {
+                        eprintln!("{e}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+        Command::NewDay { day } => match new_day(std::path::Path::new("."), day) {
+            Ok(written) => {
+                for path in written {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[rstest]
+    #[case(&["aoc", "run", "3"], true)]
+    #[case(&["aoc", "run", "3", "--part", "2", "--sample"], true)]
+    #[case(&["aoc", "run", "3", "--input", "foo.txt"], true)]
+    #[case(&["aoc", "run", "3", "--input", "-"], true)]
+    #[case(&["aoc", "run", "--all"], true)]
+    #[case(&["aoc", "run", "--all", "--mmap"], true)]
+    #[case(&["aoc", "run", "--all", "--format", "json"], true)]
+    #[case(&["aoc", "run", "3", "--format", "csv"], true)]
+    #[case(&["aoc", "bench", "9", "--sample", "--iterations", "10"], true)]
+    #[case(&["aoc", "bench", "--all", "--save", "base.json"], true)]
+    #[case(&["aoc", "bench", "--all", "--baseline", "base.json", "--threshold", "5"], true)]
+    #[case(&["aoc", "verify"], true)]
+    #[case(&["aoc", "verify", "3", "--record"], true)]
+    #[case(&["aoc", "verify", "--manifest", "other.toml"], true)]
+    #[case(&["aoc", "list"], true)]
+    #[case(&["aoc", "new-day", "12"], true)]
+    #[case(&["aoc", "fetch", "3"], true)]
+    #[case(&["aoc", "fetch", "3", "--output", "day3.txt"], true)]
+    #[case(&["aoc", "submit", "3", "2"], true)]
+    #[case(&["aoc", "samples", "7", "day7.html", "--force"], true)]
+    #[case(&["aoc", "watch", "7"], true)]
+    #[case(&["aoc", "watch", "7", "--manifest", "other.toml"], true)]
+    #[case(&["aoc", "submit", "3", "1", "--log", "tries.jsonl"], true)]
+    #[case(&["aoc", "run"], false)] // Needs a day or --all
+    #[case(&["aoc", "run", "3", "--all"], false)]
+    #[case(&["aoc", "run", "3", "--part", "3"], false)]
+    #[case(&["aoc", "run", "3", "--format", "xml"], false)]
+    #[case(&["aoc", "bench"], false)]
+    #[case(&["aoc", "new-day"], false)]
+    #[case(&["aoc", "fetch", "0"], false)]
+    #[case(&["aoc", "submit", "3"], false)]
+    #[case(&["aoc", "samples", "7"], false)]
+    #[case(&["aoc", "watch"], false)]
+    #[case(&["aoc", "watch", "13"], false)]
+    #[case(&["aoc", "submit", "3", "3"], false)]
+    #[case(&["aoc", "new-day", "13"], false)]
+    #[case(&["aoc", "run", "3", "--input", "foo.txt", "--sample"], false)]
+    fn test_cli_arguments(#[case] args: &[&str], #[case] valid: bool) {
+        assert_eq!(Cli::try_parse_from(args).is_ok(), valid);
+    }
+
+    #[rstest]
+    #[case(7, &[Stage::Parse, Stage::PartOne, Stage::PartTwo])]
+    // Day 11's part two has a sample of its own
+    #[case(11, &[Stage::Parse, Stage::PartOne, Stage::ParsePartTwo, Stage::PartTwo])]
+    fn test_bench_sample_stages(#[case] day: u8, #[case] want: &[Stage]) {
+        let measurements = bench_day(get_day(day).unwrap(), None, true, 0, 1).unwrap();
+        let stages: Vec<Stage> = measurements.iter().map(|m| m.stage).collect();
+        assert_eq!(stages, want);
+    }
 }
@@ -0,0 +1,309 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::{AocError, Block, Input, LineError};
+
+// Small parsers for the shapes puzzle input keeps coming in.  Each takes the whole `line`
+// along with the `part` of it to parse, so errors can point at the right column.  `part` must
+// be a slice of `line`, as handed out by `split`, `trim` and friends.
+
+/// A single number, ignoring surrounding whitespace
+pub fn parse_number<T>(line: &str, part: &str) -> Result<T, LineError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let part = part.trim();
+    part.parse()
+        .map_err(|e| LineError::at(line, part, format!("Invalid number {part:?}: {e}")))
+}
+
+/// Numbers separated by `delimiter`, like `1,2,3` or `4 5  6`.  Empty items, as from repeated
+/// spaces, are skipped.
+pub fn parse_list<T>(line: &str, part: &str, delimiter: &str) -> Result<Vec<T>, LineError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.split(delimiter)
+        .filter(|item| !item.trim().is_empty())
+        .map(|item| parse_number(line, item))
+        .collect()
+}
+
+/// An inclusive range written with a dash, like `3-5`.  Either end may be negative, so `-5--3`
+/// works too.
+pub fn parse_range<T>(line: &str, part: &str) -> Result<RangeInclusive<T>, LineError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let part = part.trim();
+    // Skip the first character when looking for the dash, in case it's a minus sign
+    let dash = part
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| c == '-')
+        .map(|(idx, _)| idx);
+    let Some(dash) = dash else {
+        return Err(LineError::at(
+            line,
+            part,
+            format!("Expected a range like 3-5, got {part:?}"),
+        ));
+    };
+    let start = parse_number(line, &part[..dash])?;
+    let end = parse_number(line, &part[dash + 1..])?;
+    Ok(start..=end)
+}
+
+/// A list wrapped in brackets, like `(1,3)` or `{3,5,4}`
+pub fn parse_bracketed<T>(
+    line: &str,
+    part: &str,
+    [open, close]: [char; 2],
+    delimiter: &str,
+) -> Result<Vec<T>, LineError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let part = part.trim();
+    let Some(inner) = part
+        .strip_prefix(open)
+        .and_then(|rest| rest.strip_suffix(close))
+    else {
+        return Err(LineError::at(
+            line,
+            part,
+            format!("Expected a list like {open}1{delimiter}2{close}, got {part:?}"),
+        ));
+    };
+    parse_list(line, inner, delimiter)
+}
+
+/// Splits a line like `aaa: bbb ccc` at the first `separator`, trimming both halves.  The key
+/// can't be empty, but the value can.
+pub fn parse_key_value<'a>(
+    line: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), LineError> {
+    let Some((key, value)) = line.split_once(separator) else {
+        return Err(LineError::new(
+            line.chars().count() + 1,
+            format!("Expected a key and value separated by {separator:?}"),
+        ));
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(LineError::new(
+            1,
+            format!("Expected a key before {separator:?}"),
+        ));
+    }
+    Ok((key, value.trim()))
+}
+
+/// Parses every line with `parse`, placing any error at its line in `path`
+///
+/// # Errors
+///
+/// Returns `AocError::Parse` for the first line that fails
+pub fn parse_lines<'a, T, I, F>(path: &str, lines: I, mut parse: F) -> Result<Vec<T>, AocError>
+where
+    I: IntoIterator<Item = (usize, &'a str)>,
+    F: FnMut(&'a str) -> Result<T, LineError>,
+{
+    lines
+        .into_iter()
+        .map(|(line_no, line)| parse(line).map_err(|e| e.in_file(path, line_no, line)))
+        .collect()
+}
+
+/// Exactly `N` blank line separated blocks, with `names` describing each one for errors
+///
+/// # Errors
+///
+/// Returns `AocError::Parse` if there are too few or too many blocks
+pub fn parse_blocks<'a, const N: usize>(
+    input: &'a Input,
+    names: [&str; N],
+) -> Result<[Block<'a>; N], AocError> {
+    let mut blocks = [Block::default(); N];
+    let mut paragraphs = input.paragraphs();
+    for (idx, block) in blocks.iter_mut().enumerate() {
+        let Some(paragraph) = paragraphs.next() else {
+            let line = input.lines().count() + 1;
+            return Err(AocError::parse(
+                input.path(),
+                line,
+                1,
+                "",
+                format!("Expected {} after a blank line", names[idx]),
+            ));
+        };
+        *block = paragraph;
+    }
+    if let Some(extra) = paragraphs.next() {
+        let names = match names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+            _ => names.join(""),
+        };
+        return Err(AocError::parse(
+            input.path(),
+            extra.first_line,
+            1,
+            extra.lines().next().unwrap_or_default(),
+            format!("Expected only {names}"),
+        ));
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // Parses the `part` of a line starting at `start`, so the columns are meaningful
+    fn at<T>(line: &str, start: usize, parse: impl Fn(&str, &str) -> T) -> T {
+        parse(line, &line[start..])
+    }
+
+    #[rstest]
+    #[case("x=42", 2, Ok(42))]
+    #[case("x= -7 ", 2, Ok(-7))]
+    #[case(
+        "x=4y",
+        2,
+        Err(LineError::new(3, "Invalid number \"4y\": invalid digit found in string"))
+    )]
+    fn test_parse_number(
+        #[case] line: &str,
+        #[case] start: usize,
+        #[case] want: Result<i32, LineError>,
+    ) {
+        assert_eq!(at(line, start, parse_number), want);
+    }
+
+    #[rstest]
+    #[case("1,2,3", ",", Ok(vec![1, 2, 3]))]
+    #[case("4 5  6 ", " ", Ok(vec![4, 5, 6]))]
+    #[case("", ",", Ok(vec![]))]
+    #[case(
+        "1,b",
+        ",",
+        Err(LineError::new(3, "Invalid number \"b\": invalid digit found in string"))
+    )]
+    fn test_parse_list(
+        #[case] line: &str,
+        #[case] delimiter: &str,
+        #[case] want: Result<Vec<u8>, LineError>,
+    ) {
+        assert_eq!(parse_list(line, line, delimiter), want);
+    }
+
+    #[rstest]
+    #[case("1-2,11-22", 4, Ok(11..=22))]
+    #[case("1-2,-5--3", 4, Ok(-5..=-3))]
+    #[case(
+        "1-2,11",
+        4,
+        Err(LineError::new(5, "Expected a range like 3-5, got \"11\""))
+    )]
+    #[case(
+        "1-2,-11",
+        4,
+        Err(LineError::new(5, "Expected a range like 3-5, got \"-11\""))
+    )]
+    #[case(
+        "1-2,11-x",
+        4,
+        Err(LineError::new(8, "Invalid number \"x\": invalid digit found in string"))
+    )]
+    fn test_parse_range(
+        #[case] line: &str,
+        #[case] start: usize,
+        #[case] want: Result<RangeInclusive<i64>, LineError>,
+    ) {
+        assert_eq!(at(line, start, parse_range), want);
+    }
+
+    #[rstest]
+    #[case("[.#] (1,3)", 5, ['(', ')'], Ok(vec![1, 3]))]
+    #[case("[.#] ()", 5, ['(', ')'], Ok(vec![]))]
+    #[case(
+        "[.#] (1,3",
+        5,
+        ['(', ')'],
+        Err(LineError::new(6, "Expected a list like (1,2), got \"(1,3\""))
+    )]
+    #[case(
+        "[.#] {3,y}",
+        5,
+        ['{', '}'],
+        Err(LineError::new(9, "Invalid number \"y\": invalid digit found in string"))
+    )]
+    fn test_parse_bracketed(
+        #[case] line: &str,
+        #[case] start: usize,
+        #[case] brackets: [char; 2],
+        #[case] want: Result<Vec<usize>, LineError>,
+    ) {
+        assert_eq!(
+            at(line, start, |line, part| parse_bracketed(
+                line, part, brackets, ","
+            )),
+            want
+        );
+    }
+
+    #[rstest]
+    #[case("aaa: bbb ccc", Ok(("aaa", "bbb ccc")))]
+    #[case("out:", Ok(("out", "")))]
+    #[case(
+        "aaa bbb",
+        Err(LineError::new(8, "Expected a key and value separated by \":\""))
+    )]
+    #[case(" : bbb", Err(LineError::new(1, "Expected a key before \":\"")))]
+    fn test_parse_key_value(#[case] line: &str, #[case] want: Result<(&str, &str), LineError>) {
+        assert_eq!(parse_key_value(line, ":"), want);
+    }
+
+    #[rstest]
+    fn test_parse_lines() {
+        let input = Input::from_string("test", "1,2\n3,x\n");
+        assert_eq!(
+            parse_lines(input.path(), input.numbered_lines().take(1), |line| {
+                parse_list::<u8>(line, line, ",")
+            })
+            .unwrap(),
+            [vec![1, 2]]
+        );
+        match parse_lines(input.path(), input.numbered_lines(), |line| {
+            parse_list::<u8>(line, line, ",")
+        }) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 3)),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("1-2\n\n3\n", Ok((1, 3)))]
+    #[case("1-2\n", Err((2, "Expected ingredients after a blank line".to_owned())))]
+    #[case("1-2\n\n3\n\n4\n", Err((5, "Expected only ranges and ingredients".to_owned())))]
+    fn test_parse_blocks(
+        #[case] text: &str,
+        #[case] want: Result<(usize, usize), (usize, String)>,
+    ) {
+        let input = Input::from_string("test", text);
+        let got = parse_blocks(&input, ["ranges", "ingredients"])
+            .map(|[ranges, ingredients]| (ranges.first_line, ingredients.first_line))
+            .map_err(|e| match e {
+                AocError::Parse { line, reason, .. } => (line, reason),
+                other => panic!("Expected a parse error, got {other:?}"),
+            });
+        assert_eq!(got, want);
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::LineError;
+
+/// A point, or a vector between points, on a plane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point, or a vector between points, in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Distance along one axis, without going below zero for unsigned types
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+/// Splits "1,2,3" into exactly `N` numbers, pointing any error at the coordinate responsible
+fn parse_coordinates<T, const N: usize>(line: &str, example: &str) -> Result<[T; N], LineError>
+where
+    T: FromStr + Default + Copy,
+    T::Err: fmt::Display,
+{
+    let expected = || {
+        let count = ["", "one", "two", "three"][N];
+        format!("Expected {count} coordinates, like {example}")
+    };
+    let mut coordinates = [T::default(); N];
+    let mut parts = line.split(',');
+    for coordinate in &mut coordinates {
+        let Some(part) = parts.next() else {
+            return Err(LineError::new(line.chars().count() + 1, expected()));
+        };
+        let part = part.trim();
+        *coordinate = part
+            .parse()
+            .map_err(|e| LineError::at(line, part, format!("Invalid coordinate {part:?}: {e}")))?;
+    }
+    if let Some(extra) = parts.next() {
+        return Err(LineError::at(line, extra, expected()));
+    }
+    Ok(coordinates)
+}
+
+macro_rules! impl_point {
+    ($point:ident, $example:literal, $($axis:ident),+) => {
+        impl<T> $point<T> {
+            pub const fn new($($axis: T),+) -> Self {
+                $point { $($axis),+ }
+            }
+        }
+
+        impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> $point<T> {
+            /// How far apart each axis is, as a vector with no negative parts
+            pub fn abs_diff(&self, other: &Self) -> Self {
+                $point { $($axis: abs_diff(self.$axis, other.$axis)),+ }
+            }
+
+            /// The distance moving along one axis at a time
+            pub fn manhattan(&self, other: &Self) -> T {
+                let d = self.abs_diff(other);
+                [$(d.$axis),+].into_iter().reduce(|a, b| a + b).unwrap()
+            }
+
+            /// The distance when diagonal moves cost the same as straight ones
+            pub fn chebyshev(&self, other: &Self) -> T {
+                let d = self.abs_diff(other);
+                [$(d.$axis),+].into_iter().max().unwrap()
+            }
+
+            /// The straight line distance squared, which orders points the same way as the
+            /// distance itself without needing a square root
+            pub fn distance_squared(&self, other: &Self) -> T {
+                let d = self.abs_diff(other);
+                [$(d.$axis * d.$axis),+].into_iter().reduce(|a, b| a + b).unwrap()
+            }
+
+            /// The lowest and highest corners of the smallest box holding every point, or
+            /// `None` if there aren't any
+            pub fn bounding_box<'a, I>(points: I) -> Option<(Self, Self)>
+            where
+                I: IntoIterator<Item = &'a Self>,
+                T: 'a,
+            {
+                let mut points = points.into_iter();
+                let first = *points.next()?;
+                Some(points.fold((first, first), |(low, high), point| {
+                    (
+                        $point { $($axis: low.$axis.min(point.$axis)),+ },
+                        $point { $($axis: high.$axis.max(point.$axis)),+ },
+                    )
+                }))
+            }
+        }
+
+        impl<T> $point<T>
+        where
+            T: FromStr + Default + Copy,
+            T::Err: fmt::Display,
+        {
+            #[doc = concat!("Parses comma separated coordinates, like `", $example, "`")]
+            pub fn parse(line: &str) -> Result<Self, LineError> {
+                let [$($axis),+] = parse_coordinates(line, $example)?;
+                Ok($point { $($axis),+ })
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$axis += other.$axis;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$axis -= other.$axis;)+
+            }
+        }
+
+        /// Scales every axis
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        /// Written the way puzzle inputs write them, like `1,2`
+        impl<T: fmt::Display> fmt::Display for $point<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let axes = [$(self.$axis.to_string()),+];
+                write!(f, "{}", axes.join(","))
+            }
+        }
+    };
+}
+
+impl_point!(Point2, "1,2", x, y);
+impl_point!(Point3, "1,2,3", x, y, z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_arithmetic() {
+        let mut a = Point2::new(1, 2);
+        let b = Point2::new(3, -4);
+        assert_eq!(a + b, Point2::new(4, -2));
+        assert_eq!(a - b, Point2::new(-2, 6));
+        assert_eq!(b * 2, Point2::new(6, -8));
+        assert_eq!(-b, Point2::new(-3, 4));
+        a += b;
+        assert_eq!(a, Point2::new(4, -2));
+        a -= b;
+        assert_eq!(a, (1, 2).into());
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            (2, 3, 4).into()
+        );
+    }
+
+    #[rstest]
+    #[case(Point3::new(0, 0, 0), Point3::new(3, -4, 12), 19, 12, 169)]
+    #[case(Point3::new(5, 5, 5), Point3::new(5, 5, 5), 0, 0, 0)]
+    #[case(Point3::new(-1, 2, -3), Point3::new(1, -2, 3), 12, 6, 56)]
+    fn test_distances(
+        #[case] a: Point3<i64>,
+        #[case] b: Point3<i64>,
+        #[case] manhattan: i64,
+        #[case] chebyshev: i64,
+        #[case] squared: i64,
+    ) {
+        assert_eq!(a.manhattan(&b), manhattan);
+        assert_eq!(a.chebyshev(&b), chebyshev);
+        assert_eq!(a.distance_squared(&b), squared);
+        assert_eq!(b.distance_squared(&a), squared);
+    }
+
+    #[rstest]
+    fn test_unsigned_distances() {
+        let (a, b) = (Point2::new(7_usize, 1), Point2::new(2, 5));
+        assert_eq!(a.abs_diff(&b), Point2::new(5, 4));
+        assert_eq!(b.manhattan(&a), 9);
+    }
+
+    #[rstest]
+    fn test_bounding_box() {
+        let points = [Point2::new(7, 1), Point2::new(2, 5), Point2::new(11, 3)];
+        assert_eq!(
+            Point2::bounding_box(&points),
+            Some((Point2::new(2, 1), Point2::new(11, 5)))
+        );
+        assert_eq!(Point3::<u8>::bounding_box(&[]), None);
+    }
+
+    #[rstest]
+    #[case("1,2,3", Ok(Point3::new(1, 2, 3)))]
+    #[case(" -1, 2 ,3", Ok(Point3::new(-1, 2, 3)))]
+    #[case(
+        "1,2",
+        Err(LineError::new(4, "Expected three coordinates, like 1,2,3"))
+    )]
+    #[case(
+        "1,2,3,4",
+        Err(LineError::new(7, "Expected three coordinates, like 1,2,3"))
+    )]
+    #[case(
+        "1,b,3",
+        Err(LineError::new(3, "Invalid coordinate \"b\": invalid digit found in string"))
+    )]
+    fn test_parse(#[case] line: &str, #[case] want: Result<Point3<i32>, LineError>) {
+        assert_eq!(Point3::parse(line), want);
+    }
+
+    #[rstest]
+    fn test_display() {
+        let point: Point2<u32> = Point2::parse("7,1").unwrap();
+        assert_eq!(point.to_string(), "7,1");
+        assert_eq!(Point3::new(1, -2, 3).to_string(), "1,-2,3");
+    }
+}
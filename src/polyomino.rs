@@ -0,0 +1,243 @@
+use std::fmt;
+
+use crate::AocError;
+
+/// A shape made of unit squares, drawn with `#` for filled cells and `.` for gaps.  Shapes are
+/// normalised to their bounding box, so the topmost row and leftmost column are both 0, and
+/// two shapes compare equal exactly when they cover the same cells.  Cells are `(row, column)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Polyomino {
+    /// Sorted, without repeats
+    cells: Vec<(usize, usize)>,
+    height: usize,
+    width: usize,
+}
+
+impl Polyomino {
+    /// Builds a shape from any set of cells, shifting it into its bounding box.  Repeated cells
+    /// are only counted once.
+    pub fn from_cells<I: IntoIterator<Item = (isize, isize)>>(cells: I) -> Self {
+        let cells: Vec<(isize, isize)> = cells.into_iter().collect();
+        let (Some(top), Some(left)) = (
+            cells.iter().map(|&(row, _)| row).min(),
+            cells.iter().map(|&(_, column)| column).min(),
+        ) else {
+            return Self::default();
+        };
+        let mut cells: Vec<(usize, usize)> = cells
+            .into_iter()
+            .map(|(row, column)| ((row - top) as usize, (column - left) as usize))
+            .collect();
+        cells.sort_unstable();
+        cells.dedup();
+        let height = cells.iter().map(|&(row, _)| row + 1).max().unwrap_or(0);
+        let width = cells
+            .iter()
+            .map(|&(_, column)| column + 1)
+            .max()
+            .unwrap_or(0);
+        Polyomino {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Reads a shape from lines of `#` and `.`, which can be ragged.  Rows or columns that are
+    /// all `.` around the outside are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Parse` pointing at the first character that isn't `#` or `.`
+    pub fn parse<'a, I>(path: &str, lines: I) -> Result<Self, AocError>
+    where
+        I: IntoIterator<Item = (usize, &'a str)>,
+    {
+        let mut cells = Vec::new();
+        for (row, (line_no, line)) in lines.into_iter().enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                match c {
+                    '#' => cells.push((row as isize, column as isize)),
+                    '.' => (),
+                    _ => {
+                        return Err(AocError::parse(
+                            path,
+                            line_no,
+                            column + 1,
+                            line,
+                            format!("Invalid shape character {c:?}"),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(Self::from_cells(cells))
+    }
+
+    /// The filled cells, in row-major order
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+
+    /// How many cells are filled
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        self.cells.binary_search(&(row, column)).is_ok()
+    }
+
+    /// A quarter turn clockwise
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height as isize;
+        Self::from_cells(
+            self.cells
+                .iter()
+                .map(|&(row, column)| (column as isize, height - 1 - row as isize)),
+        )
+    }
+
+    /// Mirrored left to right
+    pub fn flip(&self) -> Self {
+        let width = self.width as isize;
+        Self::from_cells(
+            self.cells
+                .iter()
+                .map(|&(row, column)| (row as isize, width - 1 - column as isize)),
+        )
+    }
+
+    /// Every distinct way the shape can be turned or flipped over, sorted, so symmetrical
+    /// shapes give fewer than eight
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+        let mut shape = self.clone();
+        for _rotate in 0..4 {
+            orientations.push(shape.flip());
+            shape = shape.rotate_cw();
+            orientations.push(shape.clone());
+        }
+        orientations.sort_unstable();
+        orientations.dedup();
+        orientations
+    }
+
+    /// The same orientation for every shape that's a turn or flip of this one, so shapes can
+    /// be compared regardless of how they were drawn
+    pub fn canonical(&self) -> Self {
+        self.orientations().swap_remove(0)
+    }
+
+    /// Each row as a bitmask, with the leftmost column in the highest bit, so `##.` is `0b110`.
+    /// Panics if the shape is more than 64 cells wide.
+    pub fn row_masks(&self) -> Vec<u64> {
+        assert!(
+            self.width <= 64,
+            "{} columns won't fit in a u64",
+            self.width
+        );
+        let mut masks = vec![0; self.height];
+        for &(row, column) in &self.cells {
+            masks[row] |= 1 << (self.width - 1 - column);
+        }
+        masks
+    }
+}
+
+/// The shape drawn with `#` and `.`, one line per row
+impl fmt::Display for Polyomino {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let c = if self.contains(row, column) { '#' } else { '.' };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+    use rstest::rstest;
+
+    fn shape(text: &str) -> Polyomino {
+        let input = Input::from_string("test", text);
+        Polyomino::parse(input.path(), input.numbered_lines()).unwrap()
+    }
+
+    #[rstest]
+    fn test_parse() {
+        // Empty rows and columns around the outside are trimmed off
+        let shape = shape("....\n.##.\n.#\n");
+        assert_eq!(shape.cells(), [(0, 0), (0, 1), (1, 0)]);
+        assert_eq!((shape.height(), shape.width(), shape.len()), (2, 2, 3));
+        assert_eq!(shape.to_string(), "##\n#.\n");
+        assert!(shape.contains(1, 0) && !shape.contains(1, 1));
+    }
+
+    #[rstest]
+    fn test_parse_error() {
+        let input = Input::from_string("test", "##\n#x\n");
+        match Polyomino::parse(input.path(), input.numbered_lines()) {
+            Err(AocError::Parse {
+                line,
+                column,
+                reason,
+                ..
+            }) => assert_eq!(
+                (line, column, reason.as_str()),
+                (2, 2, "Invalid shape character 'x'")
+            ),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_transforms() {
+        let l = shape("#.\n#.\n##\n");
+        assert_eq!(l.rotate_cw().to_string(), "###\n#..\n");
+        assert_eq!(l.flip().to_string(), ".#\n.#\n##\n");
+        assert_eq!(l.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), l);
+    }
+
+    #[rstest]
+    #[case("#\n", 1)]
+    #[case("##\n##\n", 1)]
+    #[case("###\n", 2)]
+    #[case(".#.\n###\n", 4)] // T
+    #[case("##.\n.##\n", 4)] // S
+    #[case("#.\n#.\n##\n", 8)] // L
+    fn test_orientations(#[case] text: &str, #[case] want: usize) {
+        let shape = shape(text);
+        let orientations = shape.orientations();
+        assert_eq!(orientations.len(), want);
+        assert!(orientations.contains(&shape));
+        // Every orientation has the same canonical form
+        for orientation in &orientations {
+            assert_eq!(orientation.canonical(), shape.canonical());
+        }
+    }
+
+    #[rstest]
+    fn test_row_masks() {
+        assert_eq!(shape("##.\n#..\n###\n").row_masks(), [0b110, 0b100, 0b111]);
+        assert_eq!(Polyomino::default().row_masks(), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,306 @@
+use std::fmt::Debug;
+use std::ops::{Range, RangeInclusive};
+
+/// The primitive integers, for use in `RangeSet`
+pub trait Integer: Copy + Ord + Debug {
+    /// The next value up, unless this is the largest
+    fn successor(self) -> Option<Self>;
+    /// The next value down, unless this is the smallest
+    fn predecessor(self) -> Option<Self>;
+    /// How far `self` is above `start`, which must not be greater than it
+    fn distance_from(self, start: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($wide:ty => $($t:ty),*) => {$(
+        impl Integer for $t {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn distance_from(self, start: Self) -> u128 {
+                // Widening first means the difference can't overflow, and wrapping makes
+                // i128's widest differences come out right once reinterpreted as u128
+                (self as $wide).wrapping_sub(start as $wide) as u128
+            }
+        }
+    )*};
+}
+
+impl_integer!(u128 => u8, u16, u32, u64, u128, usize);
+impl_integer!(i128 => i8, i16, i32, i64, i128, isize);
+
+/// A set of integers stored as sorted, disjoint ranges.  Overlapping and adjacent ranges are
+/// merged as they're inserted, so `3..=5` and `6..=8` become `3..=8`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    /// Inclusive (start, end) pairs, with a gap of at least one between each
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_half_open<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert_half_open(range);
+        }
+        set
+    }
+
+    /// Adds every value in `range`, merging with any ranges it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Everything before `first` ends at least two below `start`, and everything from `last`
+        // on starts at least two above `end`.  Whatever is between gets merged.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.successor().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.successor().is_none_or(|next| s <= next));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn insert_half_open(&mut self, range: Range<T>) {
+        if let Some(end) = range.end.predecessor() {
+            self.insert(range.start..=end);
+        }
+    }
+
+    /// Takes every value in `range` out of the set
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first >= last {
+            return;
+        }
+        let (left_start, _) = self.ranges[first];
+        let (_, right_end) = self.ranges[last - 1];
+        let mut kept = Vec::with_capacity(2);
+        if left_start < start {
+            // Safe, start is above something so has a predecessor
+            kept.push((left_start, start.predecessor().unwrap()));
+        }
+        if right_end > end {
+            kept.push((end.successor().unwrap(), right_end));
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn remove_half_open(&mut self, range: Range<T>) {
+        if let Some(end) = range.end.predecessor() {
+            self.remove(range.start..=end);
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(idx).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// How many values are in the set, saturating in the one case that doesn't fit: every
+    /// `u128` or `i128`
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().fold(0_u128, |total, &(s, e)| {
+            total.saturating_add(e.distance_from(s).saturating_add(1))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many separate ranges the set is made of
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The ranges in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The values missing between the first and last ranges, in ascending order
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|pair| {
+            // Safe, merging guarantees at least one value between neighbours
+            pair[0].1.successor().unwrap()..=pair[1].0.predecessor().unwrap()
+        })
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for range in other.iter() {
+            set.insert(range);
+        }
+        set
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for range in other.iter() {
+            set.remove(range);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(a), other.ranges.get(b))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever finishes first can't overlap anything else
+            if a_end < b_end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn set(ranges: &[(i32, i32)]) -> RangeSet<i32> {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    fn ranges<T: Integer>(set: &RangeSet<T>) -> Vec<(T, T)> {
+        set.iter().map(RangeInclusive::into_inner).collect()
+    }
+
+    #[rstest]
+    #[case(&[(3, 5), (10, 14), (16, 20), (12, 18)], &[(3, 5), (10, 20)])]
+    #[case(&[(3, 5), (6, 8)], &[(3, 8)])] // adjacent
+    #[case(&[(6, 8), (3, 5)], &[(3, 8)])]
+    #[case(&[(3, 5), (7, 8)], &[(3, 5), (7, 8)])]
+    #[case(&[(1, 2), (5, 6), (9, 10), (0, 20)], &[(0, 20)])]
+    #[case(&[(1, 10), (3, 4)], &[(1, 10)])]
+    #[case(&[(5, 3)], &[])] // empty
+    #[case(&[(i32::MIN, -1), (0, i32::MAX)], &[(i32::MIN, i32::MAX)])]
+    fn test_insert(#[case] inserted: &[(i32, i32)], #[case] want: &[(i32, i32)]) {
+        assert_eq!(ranges(&set(inserted)), want);
+    }
+
+    #[rstest]
+    #[case(&[(0, 10)], (3, 5), &[(0, 2), (6, 10)])]
+    #[case(&[(0, 10)], (0, 10), &[])]
+    #[case(&[(0, 10)], (-5, 3), &[(4, 10)])]
+    #[case(&[(0, 3), (5, 8), (10, 12)], (2, 10), &[(0, 1), (11, 12)])]
+    #[case(&[(0, 3)], (5, 8), &[(0, 3)])]
+    #[case(&[(i32::MIN, i32::MAX)], (i32::MIN, 0), &[(1, i32::MAX)])]
+    fn test_remove(
+        #[case] start: &[(i32, i32)],
+        #[case] (from, to): (i32, i32),
+        #[case] want: &[(i32, i32)],
+    ) {
+        let mut set = set(start);
+        set.remove(from..=to);
+        assert_eq!(ranges(&set), want);
+    }
+
+    #[rstest]
+    fn test_half_open() {
+        let mut set = RangeSet::from_half_open([0_u8..3, 3..5, 7..7]);
+        assert_eq!(ranges(&set), [(0, 4)]);
+        set.remove_half_open(1..2);
+        assert_eq!(ranges(&set), [(0, 0), (2, 4)]);
+        set.insert_half_open(0..0);
+        set.remove_half_open(0..0);
+        assert_eq!(set.len(), 4);
+    }
+
+    #[rstest]
+    #[case(2, true)]
+    #[case(3, false)]
+    #[case(5, true)]
+    #[case(9, false)]
+    #[case(-1, false)]
+    fn test_contains(#[case] value: i32, #[case] want: bool) {
+        assert_eq!(set(&[(0, 2), (5, 8)]).contains(value), want);
+    }
+
+    #[rstest]
+    fn test_len_and_gaps() {
+        let set = set(&[(0, 2), (5, 8), (10, 10)]);
+        assert_eq!(set.len(), 8);
+        assert_eq!(set.range_count(), 3);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [3..=4, 9..=9]);
+        assert!(RangeSet::<i32>::new().is_empty());
+    }
+
+    #[rstest]
+    fn test_wide_types() {
+        let set: RangeSet<u128> = [0..=u128::MAX - 1].into_iter().collect();
+        assert_eq!(set.len(), u128::MAX);
+        let full: RangeSet<u128> = [0..=u128::MAX].into_iter().collect();
+        assert_eq!(full.len(), u128::MAX); // saturated
+        let signed: RangeSet<i128> = [i128::MIN..=i128::MAX - 1].into_iter().collect();
+        assert_eq!(signed.len(), u128::MAX);
+        let small: RangeSet<i8> = [-128..=127].into_iter().collect();
+        assert_eq!(small.len(), 256);
+    }
+
+    #[rstest]
+    fn test_set_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (20, 21)]);
+        assert_eq!(ranges(&a.union(&b)), [(0, 15), (20, 21)]);
+        assert_eq!(ranges(&a.intersection(&b)), [(3, 5), (10, 12)]);
+        assert_eq!(ranges(&a.difference(&b)), [(0, 2), (13, 15)]);
+        assert_eq!(ranges(&b.difference(&a)), [(6, 9), (20, 21)]);
+        assert!(a.intersection(&set(&[(6, 9)])).is_empty());
+    }
+}
@@ -0,0 +1,188 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{Answer, InputSource, Part};
+
+/// Which profile the binary was built with, so debug runs aren't mistaken for real timings
+pub const BUILD_PROFILE: &str = if cfg!(debug_assertions) {
+    "debug"
+} else {
+    "release"
+};
+
+/// The result of solving one part of one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    /// `None` for parts that haven't been solved yet
+    pub answer: Option<String>,
+    /// Time spent reading and parsing the input, shared by both parts when they use the same file
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub input: String,
+    pub profile: &'static str,
+}
+
+impl Record {
+    pub fn new(
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        parse_time: Duration,
+        solve_time: Duration,
+        input: &InputSource,
+    ) -> Self {
+        let nanos = |time: Duration| u64::try_from(time.as_nanos()).unwrap_or(u64::MAX);
+        Record {
+            day,
+            part,
+            answer: match answer {
+                Answer::Solved(answer) => Some(answer.clone()),
+                Answer::Unsolved => None,
+            },
+            parse_ns: nanos(parse_time),
+            solve_ns: nanos(solve_time),
+            input: input.to_string(),
+            profile: BUILD_PROFILE,
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Part {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            other => Err(de::Error::custom(format!(
+                "part must be 1 or 2, not {other}"
+            ))),
+        }
+    }
+}
+
+/// Where records get written, and in which format
+pub enum Output<W: Write> {
+    /// The human readable "Day N Part One Result: ..." lines
+    Text(W),
+    /// One JSON object per line
+    Json(W),
+    /// CSV with a header row
+    Csv(Box<csv::Writer<W>>),
+}
+
+impl<W: Write> Output<W> {
+    pub fn csv(out: W) -> Self {
+        Output::Csv(Box::new(csv::Writer::from_writer(out)))
+    }
+
+    pub fn record(&mut self, record: &Record) -> io::Result<()> {
+        match self {
+            Output::Text(out) => {
+                let answer = record.answer.as_deref().unwrap_or("unsolved");
+                writeln!(
+                    out,
+                    "Day {} Part {} Result: {answer}",
+                    record.day, record.part
+                )
+            }
+            Output::Json(out) => {
+                serde_json::to_writer(&mut *out, record)?;
+                writeln!(out)
+            }
+            Output::Csv(out) => {
+                out.serialize(record)?;
+                // Flush each row, so a long --all run can be followed as it goes
+                out.flush()
+            }
+        }
+    }
+
+    /// Whether the output is meant for people rather than other programs
+    pub fn is_text(&self) -> bool {
+        matches!(self, Output::Text(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn records() -> Vec<Record> {
+        let input = InputSource::File("./data/day3_test".to_owned());
+        vec![
+            Record::new(
+                3,
+                Part::One,
+                &Answer::Solved("357".to_owned()),
+                Duration::from_micros(5),
+                Duration::from_nanos(1500),
+                &input,
+            ),
+            Record::new(
+                12,
+                Part::Two,
+                &Answer::Unsolved,
+                Duration::ZERO,
+                Duration::ZERO,
+                &InputSource::Stdin,
+            ),
+        ]
+    }
+
+    fn write_all(mut output: Output<Vec<u8>>) -> String {
+        for record in records() {
+            output.record(&record).unwrap();
+        }
+        let bytes = match output {
+            Output::Text(out) | Output::Json(out) => out,
+            Output::Csv(out) => out.into_inner().unwrap(),
+        };
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[rstest]
+    fn test_text() {
+        assert_eq!(
+            write_all(Output::Text(Vec::new())),
+            "Day 3 Part One Result: 357\nDay 12 Part Two Result: unsolved\n"
+        );
+    }
+
+    #[rstest]
+    fn test_json() {
+        let want = format!(
+            concat!(
+                r#"{{"day":3,"part":1,"answer":"357","parse_ns":5000,"solve_ns":1500,"input":"./data/day3_test","profile":"{0}"}}"#,
+                "\n",
+                r#"{{"day":12,"part":2,"answer":null,"parse_ns":0,"solve_ns":0,"input":"<stdin>","profile":"{0}"}}"#,
+                "\n"
+            ),
+            BUILD_PROFILE
+        );
+        assert_eq!(write_all(Output::Json(Vec::new())), want);
+    }
+
+    #[rstest]
+    fn test_csv() {
+        let want = format!(
+            "day,part,answer,parse_ns,solve_ns,input,profile\n\
+             3,1,357,5000,1500,./data/day3_test,{0}\n\
+             12,2,,0,0,<stdin>,{0}\n",
+            BUILD_PROFILE
+        );
+        assert_eq!(write_all(Output::csv(Vec::new())), want);
+    }
+}
@@ -0,0 +1,245 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::html::{elements, strip_tags};
+use crate::{Expected, InputKind, Manifest, Part};
+
+/// What a puzzle page shows for one part: its example input, and the answer for it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartSample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Picks the examples out of a saved puzzle page.  Each part has its own `<article>`, and
+/// part two's only shows up once part one is solved.  The example is taken to be the first
+/// `<pre><code>` block in a part, and the answer the last `<code><em>` highlight, which is how
+/// the puzzles are usually written.
+pub fn extract_samples(html: &str) -> Vec<PartSample> {
+    elements(html, "<article", "</article>")
+        .take(2)
+        .map(|article| PartSample {
+            input: elements(article, "<pre><code>", "</code></pre>")
+                .next()
+                .map(strip_tags),
+            answer: elements(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|answer| strip_tags(answer).trim().to_owned()),
+        })
+        .collect()
+}
+
+/// Why samples couldn't be saved
+#[derive(Debug)]
+pub enum SampleError {
+    /// The page had no example in part one
+    NoSample,
+    /// There's already a sample here, and it's different
+    Exists {
+        path: String,
+    },
+    Io {
+        path: String,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for SampleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SampleError::NoSample => write!(f, "No <pre><code> example found in the page"),
+            SampleError::Exists { path } => {
+                write!(
+                    f,
+                    "{path} already exists and is different, not overwriting it"
+                )
+            }
+            SampleError::Io { path, source } => write!(f, "Unable to write {path}: {source}"),
+        }
+    }
+}
+
+impl Error for SampleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SampleError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the examples as `dayN_test`, and `dayN_2_test` if part two has a different one, into
+/// `dir`, and sets the sample answers in `manifest`.  Existing files and answers that disagree
+/// are only replaced if `overwrite` is set.  Returns a line describing each change, so they can
+/// be reviewed.
+///
+/// # Errors
+///
+/// Returns `SampleError` before writing anything if part one has no example or a file would be
+/// overwritten, or if a file can't be written
+pub fn save_samples(
+    day: u8,
+    samples: &[PartSample],
+    dir: &Path,
+    manifest: &mut Manifest,
+    overwrite: bool,
+) -> Result<Vec<String>, SampleError> {
+    let Some(part_one) = samples.first().and_then(|sample| sample.input.as_ref()) else {
+        return Err(SampleError::NoSample);
+    };
+    let mut files = vec![(dir.join(format!("day{day}_test")), part_one)];
+    if let Some(part_two) = samples.get(1).and_then(|sample| sample.input.as_ref())
+        && part_two != part_one
+    {
+        files.push((dir.join(format!("day{day}_2_test")), part_two));
+    }
+
+    let mut changes = Vec::new();
+    let mut to_write = Vec::new();
+    for (path, contents) in files {
+        let display = path.display().to_string();
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == *contents => changes.push(format!("{display} unchanged")),
+            Ok(_) if !overwrite => return Err(SampleError::Exists { path: display }),
+            _ => to_write.push((path, contents)),
+        }
+    }
+    for (path, contents) in to_write {
+        let display = path.display().to_string();
+        fs::write(&path, contents).map_err(|source| SampleError::Io {
+            path: display.clone(),
+            source,
+        })?;
+        changes.push(format!("Wrote {display}"));
+    }
+
+    for (sample, part) in samples.iter().zip([Part::One, Part::Two]) {
+        let Some(answer) = &sample.answer else {
+            continue;
+        };
+        let label = format!("Day {day} Part {part} sample");
+        match manifest.expected(day, InputKind::Sample, part) {
+            Some(Expected::Clear(existing)) if existing == *answer => {
+                changes.push(format!("{label} already expects {answer}"));
+            }
+            Some(existing) if !overwrite => {
+                changes.push(format!("{label} expects {existing}, kept it over {answer}"));
+            }
+            _ => {
+                manifest.set(
+                    day,
+                    InputKind::Sample,
+                    part,
+                    &Expected::Clear(answer.clone()),
+                );
+                changes.push(format!("{label} now expects {answer}"));
+            }
+        }
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 7: Laboratories ---</h2><p>For example:</p>
+<pre><code>..S..
+.&lt;^&gt;.
+</code></pre>
+<p>Beams split like this:</p>
+<pre><code>..|..
+</code></pre>
+<p>In this example, a beam is split a total of <code><em>21</em></code> times.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the same example, there are <code><em>6</em></code> beams, then <code><em>40</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[rstest]
+    fn test_extract_samples() {
+        let samples = extract_samples(PAGE);
+        assert_eq!(
+            samples,
+            [
+                PartSample {
+                    input: Some("..S..\n.<^>.\n".to_owned()),
+                    answer: Some("21".to_owned()),
+                },
+                PartSample {
+                    input: None,
+                    answer: Some("40".to_owned()),
+                },
+            ]
+        );
+        assert_eq!(extract_samples("<p>Not a puzzle</p>"), []);
+    }
+
+    #[rstest]
+    fn test_save_samples() {
+        let dir = temp_dir("samples");
+        let mut manifest = Manifest::load(dir.join("answers.toml").to_str().unwrap()).unwrap();
+        let mut samples = extract_samples(PAGE);
+        let changes = save_samples(7, &samples, &dir, &mut manifest, false).unwrap();
+        let test_path = dir.join("day7_test");
+        assert_eq!(
+            changes,
+            [
+                format!("Wrote {}", test_path.display()),
+                "Day 7 Part One sample now expects 21".to_owned(),
+                "Day 7 Part Two sample now expects 40".to_owned(),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&test_path).unwrap(), "..S..\n.<^>.\n");
+        // Part two used the same example
+        assert!(!dir.join("day7_2_test").exists());
+        assert_eq!(
+            manifest.expected(7, InputKind::Sample, Part::Two),
+            Some(Expected::Clear("40".to_owned()))
+        );
+
+        // A different example for part two gets its own file, but won't replace part one's
+        samples[0].input = Some("changed\n".to_owned());
+        samples[1].input = Some("second\n".to_owned());
+        assert!(matches!(
+            save_samples(7, &samples, &dir, &mut manifest, false),
+            Err(SampleError::Exists { .. })
+        ));
+        assert!(!dir.join("day7_2_test").exists());
+        samples[1].answer = Some("41".to_owned());
+        let changes = save_samples(7, &samples, &dir, &mut manifest, true).unwrap();
+        assert_eq!(changes.len(), 4);
+        assert_eq!(fs::read_to_string(&test_path).unwrap(), "changed\n");
+        assert_eq!(
+            fs::read_to_string(dir.join("day7_2_test")).unwrap(),
+            "second\n"
+        );
+        assert_eq!(changes[3], "Day 7 Part Two sample now expects 41");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[rstest]
+    fn test_no_sample() {
+        let dir = temp_dir("no_samples");
+        let mut manifest = Manifest::load(dir.join("answers.toml").to_str().unwrap()).unwrap();
+        assert!(matches!(
+            save_samples(1, &[], &dir, &mut manifest, false),
+            Err(SampleError::NoSample)
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
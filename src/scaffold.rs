@@ -0,0 +1,295 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Why a new day couldn't be set up.  Nothing is written unless every check passes.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// There's already work at this path
+    Exists {
+        path: String,
+    },
+    /// The day is already in the registry or the module list
+    Registered {
+        day: u8,
+        path: String,
+    },
+    /// A file that needs a new entry isn't laid out as expected
+    Unrecognised {
+        path: String,
+        reason: String,
+    },
+    Io {
+        path: String,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists { path } => {
+                write!(f, "{path} already exists, not overwriting it")
+            }
+            ScaffoldError::Registered { day, path } => {
+                write!(f, "Day {day} is already registered in {path}")
+            }
+            ScaffoldError::Unrecognised { path, reason } => write!(f, "{path}: {reason}"),
+            ScaffoldError::Io { path, source } => write!(f, "Unable to update {path}: {source}"),
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Why a module list or registry couldn't take a new day
+#[derive(Debug, PartialEq, Eq)]
+enum Refusal {
+    Registered,
+    Unrecognised(&'static str),
+}
+
+/// The starting point for a day's solution.  It builds, and its tests pass against an empty
+/// sample, so the sample and its answers can be filled in one at a time.
+pub fn day_template(day: u8) -> String {
+    include_str!("../templates/day.rs").replace("{{day}}", &day.to_string())
+}
+
+/// Adds `pub mod dayN;` to the module list, keeping the list in the order rustfmt wants
+fn add_module(mod_rs: &str, day: u8) -> Result<String, Refusal> {
+    let name = format!("day{day}");
+    let line = format!("pub mod {name};");
+    let mut lines: Vec<&str> = mod_rs.lines().collect();
+    if lines.contains(&line.as_str()) {
+        return Err(Refusal::Registered);
+    }
+    let Some(first) = lines.iter().position(|l| l.starts_with("pub mod day")) else {
+        return Err(Refusal::Unrecognised(
+            "Expected a list of `pub mod dayN;` lines",
+        ));
+    };
+    let offset = lines[first..]
+        .iter()
+        .take_while(|l| {
+            l.strip_prefix("pub mod ")
+                .and_then(|rest| rest.strip_suffix(';'))
+                .is_some_and(|module| module.starts_with("day") && module < name.as_str())
+        })
+        .count();
+    lines.insert(first + offset, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the `DAYS` registry, in day order, and bumps the array's length to match
+fn add_to_registry(lib_rs: &str, day: u8) -> Result<String, Refusal> {
+    let entry = format!("    Day::of::<days::day{day}::Day{day}>(),");
+    if lib_rs.contains(entry.trim()) {
+        return Err(Refusal::Registered);
+    }
+    let mut lines: Vec<String> = lib_rs.lines().map(str::to_owned).collect();
+    let Some(start) = lines
+        .iter()
+        .position(|l| l.starts_with("pub static DAYS: [Day; "))
+    else {
+        return Err(Refusal::Unrecognised(
+            "Expected a `pub static DAYS: [Day; N] = [` registry",
+        ));
+    };
+    let count: usize = lines[start]
+        .trim_start_matches("pub static DAYS: [Day; ")
+        .split(']')
+        .next()
+        .and_then(|count| count.parse().ok())
+        .ok_or(Refusal::Unrecognised(
+            "Expected the registry's length to be a number",
+        ))?;
+    let Some(end) = lines[start..].iter().position(|l| l == "];") else {
+        return Err(Refusal::Unrecognised(
+            "Expected the registry to end with `];`",
+        ));
+    };
+    // Each entry is `Day::of::<days::dayN::DayN>(),`, so go after every lower N
+    let before = lines[start + 1..start + end]
+        .iter()
+        .take_while(|l| {
+            l.trim()
+                .strip_prefix("Day::of::<days::day")
+                .and_then(|rest| rest.split("::").next())
+                .and_then(|n| n.parse::<u8>().ok())
+                .is_some_and(|n| n < day)
+        })
+        .count();
+    lines.insert(start + 1 + before, entry);
+    lines[start] = lines[start].replacen(
+        &format!("[Day; {count}]"),
+        &format!("[Day; {}]", count + 1),
+        1,
+    );
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Sets up a new day under the crate at `root`: the solution skeleton in `src/days`, its
+/// module declaration and registry entry, and an empty `data/dayN_test` for the sample.
+/// Returns every path written.
+///
+/// # Errors
+///
+/// Returns `ScaffoldError` without changing anything if the day's files already exist, it's
+/// already registered, or the files it needs to update can't be read or understood
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let solution = root.join(format!("src/days/day{day}.rs"));
+    let sample = root.join(format!("data/day{day}_test"));
+    let mod_rs = root.join("src/days/mod.rs");
+    let lib_rs = root.join("src/lib.rs");
+    for path in [&solution, &sample] {
+        if path.exists() {
+            return Err(ScaffoldError::Exists {
+                path: path.display().to_string(),
+            });
+        }
+    }
+    // Work out every change before making any, so a failure leaves things as they were
+    let mut updates = Vec::new();
+    for (path, add) in [
+        (
+            &mod_rs,
+            add_module as fn(&str, u8) -> Result<String, Refusal>,
+        ),
+        (&lib_rs, add_to_registry),
+    ] {
+        let text = read(path)?;
+        let updated = add(&text, day).map_err(|refusal| {
+            let path = path.display().to_string();
+            match refusal {
+                Refusal::Registered => ScaffoldError::Registered { day, path },
+                Refusal::Unrecognised(reason) => ScaffoldError::Unrecognised {
+                    path,
+                    reason: reason.to_owned(),
+                },
+            }
+        })?;
+        updates.push((path.clone(), updated));
+    }
+    updates.push((solution, day_template(day)));
+    updates.push((sample, String::new()));
+    for (path, contents) in &updates {
+        write(path, contents)?;
+    }
+    Ok(updates.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MOD_RS: &str = "// Each day's puzzle\npub mod day1;\npub mod day10;\npub mod day2;\n";
+    const LIB_RS: &str = "\
+/// Every implemented day, in order
+pub static DAYS: [Day; 3] = [
+    Day::of::<days::day1::Day1>(),
+    Day::of::<days::day2::Day2>(),
+    Day::of::<days::day10::Day10>(),
+];
+
+pub fn get_day() {}
+";
+
+    #[rstest]
+    #[case(3, "pub mod day1;\npub mod day10;\npub mod day2;\npub mod day3;")]
+    #[case(11, "pub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;")]
+    fn test_add_module(#[case] day: u8, #[case] want: &str) {
+        let got = add_module(MOD_RS, day).unwrap();
+        assert_eq!(got, format!("// Each day's puzzle\n{want}\n"));
+    }
+
+    #[rstest]
+    fn test_add_module_twice() {
+        assert_eq!(add_module(MOD_RS, 10), Err(Refusal::Registered));
+        assert!(matches!(add_module("", 3), Err(Refusal::Unrecognised(_))));
+    }
+
+    #[rstest]
+    fn test_add_to_registry() {
+        let got = add_to_registry(LIB_RS, 3).unwrap();
+        assert_eq!(
+            got,
+            LIB_RS.replace("[Day; 3]", "[Day; 4]").replace(
+                "Day2>(),\n",
+                "Day2>(),\n    Day::of::<days::day3::Day3>(),\n"
+            )
+        );
+        let got = add_to_registry(LIB_RS, 12).unwrap();
+        assert!(got.contains("Day10>(),\n    Day::of::<days::day12::Day12>(),\n];"));
+        assert_eq!(add_to_registry(LIB_RS, 2), Err(Refusal::Registered));
+    }
+
+    #[rstest]
+    fn test_template() {
+        let template = day_template(13);
+        assert!(template.contains("pub struct Day13;"));
+        assert!(template.contains("const DAY: u8 = 13;"));
+        assert!(template.contains("./data/day13_test"));
+        assert!(!template.contains("{{day}}"));
+    }
+
+    #[rstest]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_new_day_{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), MOD_RS).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB_RS).unwrap();
+
+        let written = new_day(&root, 3).unwrap();
+        assert_eq!(written.len(), 4);
+        assert_eq!(read(&root.join("data/day3_test")).unwrap(), "");
+        assert_eq!(
+            read(&root.join("src/days/day3.rs")).unwrap(),
+            day_template(3)
+        );
+        assert!(
+            read(&root.join("src/lib.rs"))
+                .unwrap()
+                .contains("days::day3::Day3")
+        );
+
+        // A second go mustn't touch what's there
+        fs::write(root.join("src/days/day3.rs"), "work in progress").unwrap();
+        let err = new_day(&root, 3).unwrap_err();
+        assert!(matches!(err, ScaffoldError::Exists { .. }));
+        assert_eq!(
+            read(&root.join("src/days/day3.rs")).unwrap(),
+            "work in progress"
+        );
+
+        // Registered, but with its files missing
+        let err = new_day(&root, 10).unwrap_err();
+        assert!(matches!(err, ScaffoldError::Registered { day: 10, .. }));
+        assert!(!root.join("data/day10_test").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,335 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::{Graph, Grid, Interner};
+
+/// A way from the start to a goal, with both ends included in `nodes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Everything a search has seen so far.  Nodes are interned so the bookkeeping can live in
+/// `Vec`s indexed by id, with `parent` pointing back along the best known way to each one.
+struct Visited<N> {
+    nodes: Interner<N>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<N: Hash + Eq + Clone> Visited<N> {
+    fn new(start: N) -> Self {
+        let mut nodes = Interner::new();
+        nodes.intern(start);
+        Visited {
+            nodes,
+            parent: vec![None],
+        }
+    }
+
+    /// The id for `node`, and whether this is the first time it's been seen
+    fn visit(&mut self, node: N, parent: usize) -> (usize, bool) {
+        let id = self.nodes.intern(node);
+        let new = id == self.parent.len();
+        if new {
+            self.parent.push(Some(parent));
+        }
+        (id, new)
+    }
+
+    fn route<C>(&self, goal: usize, cost: C) -> Route<N, C> {
+        let mut nodes = vec![self.nodes.key(goal).clone()];
+        let mut current = goal;
+        while let Some(previous) = self.parent[current] {
+            nodes.push(self.nodes.key(previous).clone());
+            current = previous;
+        }
+        nodes.reverse();
+        Route { cost, nodes }
+    }
+}
+
+/// Breadth first search from `start` to the nearest node `is_goal` accepts, where every step
+/// costs one.  The cost of the route is the number of steps.
+pub fn bfs<N, I, FN, FG>(start: N, mut neighbours: FN, mut is_goal: FG) -> Option<Route<N, usize>>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FG: FnMut(&N) -> bool,
+{
+    if is_goal(&start) {
+        return Some(Route {
+            cost: 0,
+            nodes: vec![start],
+        });
+    }
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((id, steps)) = queue.pop_front() {
+        let node = visited.nodes.key(id).clone();
+        for next in neighbours(&node) {
+            let (next_id, new) = visited.visit(next, id);
+            if !new {
+                continue;
+            }
+            // Checked as soon as it's found, since nothing found later can be any closer
+            if is_goal(visited.nodes.key(next_id)) {
+                return Some(visited.route(next_id, steps + 1));
+            }
+            queue.push_back((next_id, steps + 1));
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start` with its distance in steps, nearest first
+pub fn bfs_distances<N, I, FN>(start: N, mut neighbours: FN) -> Vec<(N, usize)>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+{
+    let mut visited = Visited::new(start);
+    let mut distances = vec![0];
+    let mut next_up = 0;
+    // Nodes are interned in the order they're found, so ids double as the queue
+    while next_up < distances.len() {
+        let node = visited.nodes.key(next_up).clone();
+        for next in neighbours(&node) {
+            if visited.visit(next, next_up).1 {
+                distances.push(distances[next_up] + 1);
+            }
+        }
+        next_up += 1;
+    }
+    visited
+        .nodes
+        .keys()
+        .iter()
+        .cloned()
+        .zip(distances)
+        .collect()
+}
+
+/// The cheapest route from `start` to any node `is_goal` accepts, where `neighbours` gives
+/// each next node along with the cost of stepping to it.  Costs mustn't be negative.
+pub fn dijkstra<N, C, I, FN, FG>(start: N, neighbours: FN, is_goal: FG) -> Option<Route<N, C>>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// As `dijkstra`, but guided towards the goal by `heuristic`, an estimate of the cost left
+/// from a node.  The route is only guaranteed cheapest if the estimate never overshoots, and
+/// never drops by more than the cost of a step, as with Manhattan distance on a grid.
+pub fn astar<N, C, I, FN, FH, FG>(
+    start: N,
+    mut neighbours: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Route<N, C>>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut visited = Visited::new(start);
+    let mut best = vec![C::default()];
+    let mut done = vec![false];
+    while let Some(Reverse((_, id))) = queue.pop() {
+        // Stale entries are left in the queue when a cheaper way is found, so skip them
+        if done[id] {
+            continue;
+        }
+        done[id] = true;
+        let node = visited.nodes.key(id).clone();
+        if is_goal(&node) {
+            return Some(visited.route(id, best[id]));
+        }
+        for (next, step) in neighbours(&node) {
+            let cost = best[id] + step;
+            let (next_id, new) = visited.visit(next, id);
+            if new {
+                best.push(cost);
+                done.push(false);
+            } else if done[next_id] || cost >= best[next_id] {
+                continue;
+            } else {
+                best[next_id] = cost;
+                visited.parent[next_id] = Some(id);
+            }
+            let estimate = cost + heuristic(visited.nodes.key(next_id));
+            queue.push(Reverse((estimate, next_id)));
+        }
+    }
+    None
+}
+
+impl<T> Grid<T> {
+    /// Every cell reachable from `start` by orthogonal steps through cells `passable` accepts,
+    /// as a mask the same size as the grid.  `start` itself is included whatever it holds.
+    pub fn flood_fill<F: FnMut(&T) -> bool>(
+        &self,
+        start: (usize, usize),
+        mut passable: F,
+    ) -> Grid<bool> {
+        let mut reached = Grid::new(self.width(), self.height(), false);
+        reached[start] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some((row, column)) = queue.pop_front() {
+            for next in self.neighbours4(row, column) {
+                if !reached[next] && passable(&self[next]) {
+                    reached[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        reached
+    }
+
+    /// The fewest orthogonal steps from `start` to `goal` through cells `passable` accepts
+    pub fn shortest_path<F: FnMut(&T) -> bool>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        mut passable: F,
+    ) -> Option<Route<(usize, usize), usize>> {
+        bfs(
+            start,
+            |&(row, column)| {
+                self.neighbours4(row, column)
+                    .filter(|&next| passable(&self[next]))
+                    .collect::<Vec<_>>()
+            },
+            |&position| position == goal,
+        )
+    }
+}
+
+impl<K> Graph<K> {
+    /// The fewest edges to follow from `from` to `to`
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<Route<usize, usize>> {
+        bfs(
+            from,
+            |&id| self.successors(id).iter().copied(),
+            |&id| id == to,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+    use rstest::rstest;
+
+    fn maze() -> Grid<char> {
+        let text = "S.#.....\n.##.###.\n....#...\n.####.#.\n......#G\n";
+        Grid::parse(&Input::from_string("test", text), Some).unwrap()
+    }
+
+    #[rstest]
+    fn test_bfs() {
+        // Counting up by doubling or adding one
+        let route = bfs(1_u32, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+        assert_eq!(route.cost, 4);
+        assert_eq!(route.nodes, [1, 2, 4, 5, 10]);
+        assert_eq!(bfs(3, |&n: &u32| [n + 1], |&n| n == 3).unwrap().nodes, [3]);
+        assert!(bfs(0_u8, |&n| (n < 5).then_some(n + 1), |&n| n == 9).is_none());
+    }
+
+    #[rstest]
+    fn test_bfs_distances() {
+        let distances = bfs_distances(0_u8, |&n| (n < 3).then_some(n + 1));
+        assert_eq!(distances, [(0, 0), (1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[rstest]
+    fn test_dijkstra() {
+        // The direct edge is dearer than going round
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+        let route = dijkstra('a', edges, |&node| node == 'd').unwrap();
+        assert_eq!((route.cost, route.nodes), (6, vec!['a', 'b', 'c', 'd']));
+        assert!(dijkstra('b', edges, |&node| node == 'a').is_none());
+    }
+
+    #[rstest]
+    fn test_astar_matches_dijkstra() {
+        let grid = maze();
+        let goal = grid.position(|&c| c == 'G').unwrap();
+        let neighbours = |&(row, column): &(usize, usize)| {
+            grid.neighbours4(row, column)
+                .filter(|&next| grid[next] != '#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let manhattan =
+            |&(row, column): &(usize, usize)| row.abs_diff(goal.0) + column.abs_diff(goal.1);
+        let guided = astar((0, 0), neighbours, manhattan, |&p| p == goal).unwrap();
+        let plain = dijkstra((0, 0), neighbours, |&p| p == goal).unwrap();
+        assert_eq!(guided.cost, 15);
+        assert_eq!(plain.cost, 15);
+        assert_eq!(guided.nodes.len(), 16);
+    }
+
+    #[rstest]
+    fn test_grid_shortest_path() {
+        let grid = maze();
+        let goal = grid.position(|&c| c == 'G').unwrap();
+        let route = grid.shortest_path((0, 0), goal, |&c| c != '#').unwrap();
+        assert_eq!(route.cost, 15);
+        assert_eq!(route.nodes.first(), Some(&(0, 0)));
+        assert_eq!(route.nodes.last(), Some(&goal));
+        // Every step is to a neighbour
+        for pair in route.nodes.windows(2) {
+            assert_eq!(
+                pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1),
+                1
+            );
+        }
+        // The goal itself has to be passable
+        assert!(grid.shortest_path((0, 0), goal, |&c| c == '.').is_none());
+    }
+
+    #[rstest]
+    fn test_flood_fill() {
+        let grid = maze();
+        let reached = grid.flood_fill((0, 3), |&c| c != '#');
+        assert_eq!(reached.iter().filter(|&(_, &r)| r).count(), 27);
+        // Walls are never reached
+        assert!(!reached[(0, 2)]);
+        let walled_in = Grid::parse(&Input::from_string("test", "#.#\n###\n"), Some).unwrap();
+        let reached = walled_in.flood_fill((0, 1), |&c| c == '.');
+        assert_eq!(reached.to_string(), "falsetruefalse\nfalsefalsefalse\n");
+    }
+
+    #[rstest]
+    fn test_graph_shortest_path() {
+        let mut graph = Graph::new();
+        for (from, to) in [("a", "b"), ("b", "c"), ("c", "d"), ("a", "c")] {
+            graph.add_edge(from, to);
+        }
+        let (a, d) = (graph.id("a").unwrap(), graph.id("d").unwrap());
+        let route = graph.shortest_path(a, d).unwrap();
+        let names: Vec<&str> = route.nodes.iter().map(|&id| *graph.name(id)).collect();
+        assert_eq!((route.cost, names), (2, vec!["a", "c", "d"]));
+        assert!(graph.shortest_path(d, a).is_none());
+    }
+}
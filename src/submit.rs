@@ -0,0 +1,490 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::html::{elements, strip_tags};
+use crate::{AocError, Client, ClientError, Part};
+
+/// Where every submission gets recorded unless told otherwise
+pub const ATTEMPTS_PATH: &str = "./data/attempts.jsonl";
+
+/// How long to hold off after a wrong answer.  The site makes you wait at least this long, and
+/// longer after repeated mistakes, which it reports as `Outcome::RateLimited`.
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What the site made of an answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way
+    Wrong,
+    /// Submitted too soon after the last one, and not checked
+    RateLimited {
+        wait_secs: Option<u64>,
+    },
+    /// The part was already solved, or part one isn't solved yet
+    WrongLevel,
+    /// A page that didn't look like any of the above, trimmed down to its text
+    Unrecognised(String),
+}
+
+impl Outcome {
+    /// Classifies the page the site sends back after a submission
+    pub fn from_page(page: &str) -> Self {
+        // Only the main article matters, the rest is navigation and styling
+        let article = elements(page, "<article", "</article>")
+            .next()
+            .unwrap_or(page);
+        let text = strip_tags(article)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait_secs: wait_from(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unrecognised(text)
+        }
+    }
+
+    /// Whether the site checked the answer and said no
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited {
+                wait_secs: Some(secs),
+            } => write!(f, "rate limited, {secs}s left to wait"),
+            Outcome::RateLimited { wait_secs: None } => write!(f, "rate limited"),
+            Outcome::WrongLevel => write!(f, "not the right level, is it already solved?"),
+            Outcome::Unrecognised(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Reads a wait like "You have 1m 5s left to wait" in seconds
+fn wait_from(text: &str) -> Option<u64> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// One submission and what came of it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch
+    pub at: u64,
+}
+
+/// Why an answer wasn't sent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part's already been solved, with this answer
+    Solved { answer: String },
+    /// This exact answer was already sent, and was wrong
+    AlreadyTried { outcome: Outcome },
+    /// An earlier answer was too high (or low), and this one is at least as high (or low)
+    OutOfBounds { outcome: Outcome, previous: String },
+    /// Too soon after the last submission
+    Cooldown { wait: Duration },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "Already solved, the answer was {answer}"),
+            Refusal::AlreadyTried { outcome } => {
+                write!(f, "Already submitted that answer, and it was {outcome}")
+            }
+            Refusal::OutOfBounds { outcome, previous } => {
+                write!(f, "Not submitting, {previous} was already {outcome}")
+            }
+            Refusal::Cooldown { wait } => {
+                write!(
+                    f,
+                    "Too soon after the last answer, wait {}s",
+                    wait.as_secs()
+                )
+            }
+        }
+    }
+}
+
+/// Everything that can stop a submission
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    /// The attempts log couldn't be read or written
+    Log(AocError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "{refusal}"),
+            SubmitError::Client(e) => write!(f, "{e}"),
+            SubmitError::Log(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SubmitError::Refused(_) => None,
+            SubmitError::Client(e) => Some(e),
+            SubmitError::Log(e) => Some(e),
+        }
+    }
+}
+
+/// Every answer ever submitted, one JSON object per line, so mistakes aren't repeated
+#[derive(Debug, Clone)]
+pub struct AttemptLog {
+    path: String,
+    attempts: Vec<Attempt>,
+}
+
+impl AttemptLog {
+    /// Loads the log, treating a missing file as an empty one
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be read, or `AocError::Parse` for a line that
+    /// isn't an attempt
+    pub fn load(path: &str) -> Result<Self, AocError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_owned(),
+                    source,
+                });
+            }
+        };
+        let attempts = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| AocError::parse(path, idx + 1, e.column(), line, e.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(AttemptLog {
+            path: path.to_owned(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Appends to the file straight away, so nothing is lost if a later step fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::Io` if the file can't be written
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), AocError> {
+        let io_error = |source| AocError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        let mut line = serde_json::to_string(&attempt).expect("attempts always serialise");
+        line.push('\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(io_error)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Whether `answer` is worth sending at `now`, going by everything sent before
+    ///
+    /// # Errors
+    ///
+    /// Returns the `Refusal` if it isn't
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: SystemTime) -> Result<(), Refusal> {
+        let this_part = || {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.day == day && attempt.part == part)
+        };
+        if let Some(solved) = this_part().find(|attempt| attempt.outcome == Outcome::Correct) {
+            return Err(Refusal::Solved {
+                answer: solved.answer.clone(),
+            });
+        }
+        if let Some(tried) =
+            this_part().find(|attempt| attempt.answer == answer && attempt.outcome.is_wrong())
+        {
+            return Err(Refusal::AlreadyTried {
+                outcome: tried.outcome.clone(),
+            });
+        }
+        // Too high and too low give bounds, for answers that are numbers
+        if let Ok(number) = answer.parse::<i128>() {
+            for attempt in this_part() {
+                let Ok(previous) = attempt.answer.parse::<i128>() else {
+                    continue;
+                };
+                let out_of_bounds = match attempt.outcome {
+                    Outcome::TooHigh => number >= previous,
+                    Outcome::TooLow => number <= previous,
+                    _ => false,
+                };
+                if out_of_bounds {
+                    return Err(Refusal::OutOfBounds {
+                        outcome: attempt.outcome.clone(),
+                        previous: attempt.answer.clone(),
+                    });
+                }
+            }
+        }
+        // Any day's submission holds up every other day
+        if let Some(last) = self.attempts.last() {
+            let wait = match last.outcome {
+                Outcome::RateLimited { wait_secs } => {
+                    wait_secs.map_or(COOLDOWN, Duration::from_secs)
+                }
+                ref outcome if outcome.is_wrong() => COOLDOWN,
+                _ => Duration::ZERO,
+            };
+            let ready = last.at + wait.as_secs();
+            let now = unix_secs(now);
+            if now < ready {
+                return Err(Refusal::Cooldown {
+                    wait: Duration::from_secs(ready - now),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sends `answer` for a day's part, unless the log says there's no point, and records what the
+/// site said about it
+///
+/// # Errors
+///
+/// Returns `SubmitError::Refused` without sending anything if the log rules the answer out, or
+/// the error if sending it or recording it fails
+pub fn submit(
+    client: &Client,
+    log: &mut AttemptLog,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: SystemTime,
+) -> Result<Outcome, SubmitError> {
+    log.check(day, part, answer, now)
+        .map_err(SubmitError::Refused)?;
+    let level = match part {
+        Part::One => "1",
+        Part::Two => "2",
+    };
+    let page = client
+        .post(
+            &format!("/day/{day}/answer"),
+            &[("level", level), ("answer", answer)],
+        )
+        .map_err(SubmitError::Client)?;
+    let outcome = Outcome::from_page(&page);
+    log.record(Attempt {
+        day,
+        part,
+        answer: answer.to_owned(),
+        outcome: outcome.clone(),
+        at: unix_secs(now),
+    })
+    .map_err(SubmitError::Log)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::MockServer;
+    use rstest::rstest;
+
+    const TOO_HIGH: &str = "<html><body><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, make sure you're using the full input \
+        data.  Please wait one minute before trying again.</p></article></main></body></html>";
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are \
+        <span class=\"day-success\">one gold star</span> closer.</p></article></main>";
+
+    fn temp_log(name: &str) -> AttemptLog {
+        let path = std::env::temp_dir().join(format!("aoc_{name}_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        AttemptLog::load(path.to_str().unwrap()).unwrap()
+    }
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[rstest]
+    #[case(CORRECT, Outcome::Correct)]
+    #[case(TOO_HIGH, Outcome::TooHigh)]
+    #[case(
+        "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        Outcome::TooLow
+    )]
+    #[case(
+        "<article><p>That's not the right answer.  If you're stuck...</p></article>",
+        Outcome::Wrong
+    )]
+    #[case(
+        "<article><p>You gave an answer too recently; you have to wait after submitting an \
+            answer before trying again.  You have 1m 5s left to wait.</p></article>",
+        Outcome::RateLimited { wait_secs: Some(65) }
+    )]
+    #[case(
+        "<article><p>You don't seem to be solving the right level.  Did you already complete \
+            it?</p></article>",
+        Outcome::WrongLevel
+    )]
+    #[case(
+        "<article><p>Something <em>else</em></p></article>",
+        Outcome::Unrecognised("Something else".to_owned())
+    )]
+    fn test_outcome_from_page(#[case] page: &str, #[case] want: Outcome) {
+        assert_eq!(Outcome::from_page(page), want);
+    }
+
+    #[rstest]
+    fn test_check() {
+        let mut log = temp_log("attempts_check");
+        let attempt = |part, answer: &str, outcome, at| Attempt {
+            day: 3,
+            part,
+            answer: answer.to_owned(),
+            outcome,
+            at,
+        };
+        log.record(attempt(Part::One, "500", Outcome::TooHigh, 1000))
+            .unwrap();
+        assert_eq!(
+            log.check(3, Part::One, "400", at(1030)),
+            Err(Refusal::Cooldown {
+                wait: Duration::from_secs(30)
+            })
+        );
+        assert_eq!(log.check(3, Part::One, "400", at(1060)), Ok(()));
+        assert_eq!(
+            log.check(3, Part::One, "500", at(1060)),
+            Err(Refusal::AlreadyTried {
+                outcome: Outcome::TooHigh
+            })
+        );
+        assert_eq!(
+            log.check(3, Part::One, "600", at(1060)),
+            Err(Refusal::OutOfBounds {
+                outcome: Outcome::TooHigh,
+                previous: "500".to_owned()
+            })
+        );
+        // The other part has its own answers
+        assert_eq!(log.check(3, Part::Two, "500", at(1060)), Ok(()));
+
+        log.record(attempt(Part::One, "450", Outcome::Correct, 2000))
+            .unwrap();
+        assert_eq!(
+            log.check(3, Part::One, "460", at(2001)),
+            Err(Refusal::Solved {
+                answer: "450".to_owned()
+            })
+        );
+        // No cooldown after a right answer
+        assert_eq!(log.check(3, Part::Two, "12", at(2001)), Ok(()));
+
+        // Everything survives a reload
+        let reloaded = AttemptLog::load(&log.path).unwrap();
+        assert_eq!(reloaded.attempts(), log.attempts());
+        fs::remove_file(&log.path).unwrap();
+    }
+
+    #[rstest]
+    fn test_submit() {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(server.config());
+        let mut log = temp_log("attempts_submit");
+
+        let outcome = submit(&client, &mut log, 3, Part::Two, "500", at(1000)).unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        // Refused locally, so the server never sees these
+        assert!(matches!(
+            submit(&client, &mut log, 3, Part::Two, "500", at(2000)),
+            Err(SubmitError::Refused(Refusal::AlreadyTried { .. }))
+        ));
+        assert!(matches!(
+            submit(&client, &mut log, 3, Part::Two, "450", at(1010)),
+            Err(SubmitError::Refused(Refusal::Cooldown { .. }))
+        ));
+        let outcome = submit(&client, &mut log, 3, Part::Two, "450", at(2000)).unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(log.attempts().len(), 2);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2025/day/3/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=500"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=2&answer=450"));
+        fs::remove_file(&log.path).unwrap();
+    }
+}
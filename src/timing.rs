@@ -0,0 +1,273 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{Align, Table, format_duration, trace};
+
+/// One named span in the timing tree.  Spans with the same name under the same parent are
+/// merged, so a timer started in a loop shows up once with its total time and a count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: String,
+    /// Time spent across every run of this span
+    pub total: Duration,
+    /// How many times the span finished
+    pub count: usize,
+    pub children: Vec<Span>,
+}
+
+impl Span {
+    fn new(name: &str) -> Self {
+        Span {
+            name: name.to_owned(),
+            total: Duration::ZERO,
+            count: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// The spans recorded on one thread
+#[derive(Default)]
+struct Recorder {
+    roots: Vec<Span>,
+    /// Child indexes from the roots down to the innermost running span
+    open: Vec<usize>,
+    /// Bumped every time the spans are taken, so older timers don't record into the new tree
+    generation: usize,
+}
+
+impl Recorder {
+    fn span_mut(&mut self, path: &[usize]) -> Option<&mut Span> {
+        let (&first, rest) = path.split_first()?;
+        let mut span = self.roots.get_mut(first)?;
+        for &idx in rest {
+            span = span.children.get_mut(idx)?;
+        }
+        Some(span)
+    }
+
+    fn enter(&mut self, name: &str) -> Vec<usize> {
+        let open = self.open.clone();
+        let siblings = match self.span_mut(&open) {
+            Some(parent) => &mut parent.children,
+            None => &mut self.roots,
+        };
+        let idx = match siblings.iter().position(|span| span.name == name) {
+            Some(idx) => idx,
+            None => {
+                siblings.push(Span::new(name));
+                siblings.len() - 1
+            }
+        };
+        self.open.push(idx);
+        self.open.clone()
+    }
+
+    fn exit(&mut self, path: &[usize], took: Duration) {
+        // Timers normally finish innermost first, but if a parent is dropped early its
+        // unfinished children are closed with it.
+        if self.open.starts_with(path) {
+            self.open.truncate(path.len() - 1);
+        }
+        if let Some(span) = self.span_mut(path) {
+            span.total += took;
+            span.count += 1;
+        }
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::default();
+}
+
+/// Takes every span recorded on this thread so far, leaving an empty tree behind.
+/// Timers are per thread, so anything timed inside a rayon worker won't appear here.
+pub fn take_timings() -> Timings {
+    RECORDER.with_borrow_mut(|recorder| {
+        recorder.open.clear();
+        recorder.generation += 1;
+        Timings {
+            spans: std::mem::take(&mut recorder.roots),
+        }
+    })
+}
+
+/// A tree of recorded spans, which displays as an indented summary
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub spans: Vec<Span>,
+}
+
+impl Timings {
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn rows(spans: &[Span], depth: usize, parent: Option<Duration>, table: &mut Table) {
+            for span in spans {
+                let mut label = format!("{}{}", "  ".repeat(depth), span.name);
+                if span.count > 1 {
+                    label.push_str(&format!(" (x{})", span.count));
+                }
+                let mut row = vec![label, format_duration(span.total)];
+                if let Some(parent) = parent.filter(|parent| !parent.is_zero()) {
+                    let share = 100.0 * span.total.as_secs_f64() / parent.as_secs_f64();
+                    row.push(format!("{share:.1}%"));
+                }
+                table.push(row);
+                rows(&span.children, depth + 1, Some(span.total), table);
+            }
+        }
+
+        let mut table = Table::new([Align::Left, Align::Right, Align::Right]);
+        rows(&self.spans, 0, None, &mut table);
+        write!(f, "{table}")
+    }
+}
+
+/// Times from `start` until it is dropped, recording the result as a span nested under
+/// whichever timers are already running on this thread
+pub struct Timer {
+    start_time: Instant,
+    name: Cow<'static, str>,
+    path: Vec<usize>,
+    generation: usize,
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let took = self.duration();
+        RECORDER.with_borrow_mut(|recorder| {
+            if recorder.generation == self.generation {
+                recorder.exit(&self.path, took);
+            }
+        });
+    }
+}
+
+impl Timer {
+    pub fn start<T>(name: T) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let name: Cow<'static, str> = name.into();
+        trace!("Creating timer called \"{}\"", name);
+        let (path, generation) =
+            RECORDER.with_borrow_mut(|recorder| (recorder.enter(&name), recorder.generation));
+        Timer {
+            start_time: Instant::now(),
+            name,
+            path,
+            generation,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.start_time.elapsed()
+    }
+
+    /// Print how long the timer has been running, without waiting for the summary
+    pub fn elapsed(&self) {
+        println!("[{}] took {}", self.name, format_duration(self.duration()));
+    }
+
+    pub fn secs_so_far(&self) -> u64 {
+        self.start_time.elapsed().as_secs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(spans: &[Span]) -> Vec<(&str, usize, Vec<&str>)> {
+        spans
+            .iter()
+            .map(|span| {
+                let children = span.children.iter().map(|c| c.name.as_str()).collect();
+                (span.name.as_str(), span.count, children)
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_nested_spans() {
+        take_timings();
+        {
+            let _day = Timer::start("Day");
+            {
+                let _parse = Timer::start("Parse");
+                let _read = Timer::start("Read");
+            }
+            for _ in 0..3 {
+                let _part = Timer::start("Part");
+            }
+        }
+        let other = Timer::start("Other");
+        drop(other);
+        let timings = take_timings();
+        assert_eq!(
+            names(&timings.spans),
+            [("Day", 1, vec!["Parse", "Part"]), ("Other", 1, vec![])]
+        );
+        let day = &timings.spans[0];
+        assert_eq!(names(&day.children[0].children), [("Read", 1, vec![])]);
+        assert_eq!(day.children[1].count, 3);
+        assert!(day.total >= day.children.iter().map(|c| c.total).sum());
+        assert!(take_timings().is_empty());
+    }
+
+    #[rstest]
+    fn test_parent_dropped_first() {
+        take_timings();
+        let parent = Timer::start("Parent");
+        let child = Timer::start("Child");
+        drop(parent);
+        let sibling = Timer::start("Sibling");
+        drop(child);
+        drop(sibling);
+        let timings = take_timings();
+        assert_eq!(
+            names(&timings.spans),
+            [("Parent", 1, vec!["Child"]), ("Sibling", 1, vec![])]
+        );
+        assert_eq!(timings.spans[0].children[0].count, 1);
+    }
+
+    #[rstest]
+    fn test_timer_outliving_take() {
+        take_timings();
+        let timer = Timer::start("Old");
+        take_timings();
+        drop(timer);
+        assert!(take_timings().is_empty());
+    }
+
+    #[rstest]
+    fn test_display() {
+        let span = |name: &str, millis, count, children| Span {
+            name: name.to_owned(),
+            total: Duration::from_millis(millis),
+            count,
+            children,
+        };
+        let timings = Timings {
+            spans: vec![span(
+                "Day 1",
+                200,
+                1,
+                vec![span("Parse", 50, 1, vec![]), span("Part", 150, 2, vec![])],
+            )],
+        };
+        assert_eq!(
+            timings.to_string(),
+            "Day 1        200.0ms\n  Parse       50.0ms  25.0%\n  Part (x2)  150.0ms  75.0%\n"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::hash::Hash;
+
+use crate::Interner;
+
+/// Disjoint sets over the ids `0..len`, merged by size with path compression, so `find` and
+/// `union` are effectively constant time
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    num_groups: usize,
+}
+
+impl UnionFind {
+    /// Starts with every id in a group of its own
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            num_groups: len,
+        }
+    }
+
+    /// Adds a new id in a group of its own, returning it
+    pub fn add(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.size.push(1);
+        self.num_groups += 1;
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn num_groups(&self) -> usize {
+        self.num_groups
+    }
+
+    /// The representative of the group `id` is in.  Panics if `id` is out of range.
+    pub fn find(&mut self, id: usize) -> usize {
+        let root = self.root(id);
+        // Point everything on the way straight at the root.  Done in a loop rather than
+        // recursively, so long chains can't overflow the stack.
+        let mut current = id;
+        while current != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// As `find`, without compressing the path, for when only a shared reference is available
+    pub fn root(&self, mut id: usize) -> usize {
+        while self.parent[id] != id {
+            id = self.parent[id];
+        }
+        id
+    }
+
+    /// Merges the groups `a` and `b` are in, returning false if they already shared one
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+        // Hang the smaller tree off the bigger one, to keep the trees shallow
+        let (big, small) = if self.size[root_a] < self.size[root_b] {
+            (root_b, root_a)
+        } else {
+            (root_a, root_b)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.num_groups -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many ids are in the same group as `id`, including itself
+    pub fn group_size(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    /// The size of every group, in no particular order
+    pub fn group_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&id| self.parent[id] == id)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// The members of every group.  Groups are ordered by their lowest id, and members are in
+    /// ascending order.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut group_of_root = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.num_groups);
+        for id in 0..self.len() {
+            let root = self.find(id);
+            if group_of_root[root] == usize::MAX {
+                group_of_root[root] = components.len();
+                components.push(Vec::with_capacity(self.size[root]));
+            }
+            components[group_of_root[root]].push(id);
+        }
+        components
+    }
+}
+
+/// A `UnionFind` over arbitrary keys, interning each key the first time it's mentioned
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    interner: Interner<K>,
+    sets: UnionFind,
+}
+
+impl<K> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        KeyedUnionFind {
+            interner: Interner::default(),
+            sets: UnionFind::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `key` in a group of its own if it's new, returning its id
+    pub fn insert(&mut self, key: K) -> usize {
+        let id = self.interner.intern(key);
+        if id == self.sets.len() {
+            self.sets.add();
+        }
+        id
+    }
+
+    /// Merges the groups of `a` and `b`, adding either if they're new.  Returns false if they
+    /// were already in the same group.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Whether both keys are known and in the same group
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.interner.get(a), self.interner.get(b)) {
+            (Some(a), Some(b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    pub fn num_groups(&self) -> usize {
+        self.sets.num_groups()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+
+    /// The members of every group, in the order keys were first inserted
+    pub fn components(&mut self) -> Vec<Vec<&K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| self.interner.key(id)).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.num_groups(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert_eq!(sets.num_groups(), 3);
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.group_size(2), 4);
+        assert_eq!(sets.components(), [vec![0, 1, 2, 3], vec![4], vec![5]]);
+        let mut sizes = sets.group_sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 1, 4]);
+        let added = sets.add();
+        assert_eq!((added, sets.num_groups()), (6, 4));
+    }
+
+    #[rstest]
+    fn test_long_chain() {
+        // Deep enough that a recursive find would overflow the test thread's stack
+        let n = 1_000_000;
+        let mut sets = UnionFind::new(n);
+        for id in 1..n {
+            // Join the chain's root onto a fresh single, making the tree one deeper each time
+            sets.parent[id - 1] = id;
+            sets.size[id] += sets.size[id - 1];
+        }
+        sets.num_groups = 1;
+        assert_eq!(sets.root(0), n - 1);
+        assert_eq!(sets.find(0), n - 1);
+        // Fully compressed now
+        assert_eq!(sets.parent[n / 2], n - 1);
+    }
+
+    #[rstest]
+    fn test_keyed() {
+        let mut sets = KeyedUnionFind::new();
+        assert!(sets.union("a", "b"));
+        assert!(sets.union("c", "d"));
+        sets.insert("e");
+        assert!(sets.union("b", "c"));
+        assert!(!sets.union("a", "d"));
+        assert!(sets.connected(&"a", &"d"));
+        assert!(!sets.connected(&"a", &"e"));
+        assert!(!sets.connected(&"a", &"z"));
+        assert_eq!(sets.num_groups(), 2);
+        assert_eq!(sets.len(), 5);
+        assert_eq!(
+            sets.components(),
+            [vec![&"a", &"b", &"c", &"d"], vec![&"e"]]
+        );
+    }
+}
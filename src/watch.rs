@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+use crate::{Answer, Expected, InputKind, Manifest, Part, Verdict, verdict};
+
+/// How long things have to stay quiet after a change before re-running.  Editors and `cargo fmt`
+/// tend to touch a file several times when saving it.
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The answers from one run of a day, for each input and part that got solved
+pub type Answers = HashMap<(InputKind, Part), Answer>;
+
+/// Waits for changes to one day's source or data.  Inputs kept outside `data/`, through
+/// `AOC_INPUT_DIR` or `AOC_DAYN_INPUT`, aren't watched.
+pub struct DayWatcher {
+    day: u8,
+    root: PathBuf,
+    events: Receiver<notify::Result<Event>>,
+    debounce: Debounce,
+    // Dropping this stops the events
+    _watcher: RecommendedWatcher,
+}
+
+/// Why watching failed
+#[derive(Debug)]
+pub enum WatchError {
+    Notify(notify::Error),
+    /// The watcher went away, so nothing more will arrive
+    Disconnected,
+}
+
+impl fmt::Display for WatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WatchError::Notify(e) => write!(f, "Unable to watch for changes: {e}"),
+            WatchError::Disconnected => write!(f, "Stopped receiving changes"),
+        }
+    }
+}
+
+impl Error for WatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WatchError::Notify(e) => Some(e),
+            WatchError::Disconnected => None,
+        }
+    }
+}
+
+impl From<notify::Error> for WatchError {
+    fn from(e: notify::Error) -> Self {
+        WatchError::Notify(e)
+    }
+}
+
+impl DayWatcher {
+    /// Starts watching `src` and `data` under `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns `WatchError` if either directory can't be watched
+    pub fn new(day: u8, root: &Path) -> Result<Self, WatchError> {
+        let root = root
+            .canonicalize()
+            .map_err(|e| notify::Error::io(e).add_path(root.to_path_buf()))?;
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&root.join("src"), RecursiveMode::Recursive)?;
+        watcher.watch(&root.join("data"), RecursiveMode::NonRecursive)?;
+        Ok(DayWatcher {
+            day,
+            root,
+            events,
+            debounce: Debounce::new(DEBOUNCE),
+            _watcher: watcher,
+        })
+    }
+
+    /// Blocks until something relevant has changed and then settled, returning what changed
+    /// relative to the root
+    ///
+    /// # Errors
+    ///
+    /// Returns `WatchError` if the watcher fails
+    pub fn wait(&mut self) -> Result<Vec<PathBuf>, WatchError> {
+        let mut changed = Vec::new();
+        loop {
+            let received = match self.debounce.remaining(Instant::now()) {
+                Some(wait) => self.events.recv_timeout(wait),
+                None => self
+                    .events
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok(event) => {
+                    let event = event?;
+                    // Reads don't change anything, and running the day causes plenty of them
+                    let paths = if event.kind.is_access() {
+                        Vec::new()
+                    } else {
+                        event.paths
+                    };
+                    for path in paths {
+                        let Ok(relative) = path.strip_prefix(&self.root) else {
+                            continue;
+                        };
+                        if is_relevant(self.day, relative) {
+                            if !changed.iter().any(|seen| seen == relative) {
+                                changed.push(relative.to_path_buf());
+                            }
+                            self.debounce.changed(Instant::now());
+                        }
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => (),
+                Err(RecvTimeoutError::Disconnected) => return Err(WatchError::Disconnected),
+            }
+            if self.debounce.ready(Instant::now()) {
+                return Ok(changed);
+            }
+        }
+    }
+}
+
+/// Holds off until changes stop arriving for `delay`
+#[derive(Debug)]
+struct Debounce {
+    delay: Duration,
+    last_change: Option<Instant>,
+}
+
+impl Debounce {
+    fn new(delay: Duration) -> Self {
+        Debounce {
+            delay,
+            last_change: None,
+        }
+    }
+
+    fn changed(&mut self, now: Instant) {
+        self.last_change = Some(now);
+    }
+
+    /// How much longer to wait, or `None` if nothing has changed
+    fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.last_change
+            .map(|last| (last + self.delay).saturating_duration_since(now))
+    }
+
+    /// Whether changes have settled, which starts the wait over
+    fn ready(&mut self, now: Instant) -> bool {
+        let ready = self.remaining(now) == Some(Duration::ZERO);
+        if ready {
+            self.last_change = None;
+        }
+        ready
+    }
+}
+
+/// Whether a change to `path`, relative to the root, could change the day's answers.  That's the
+/// day's own module, anything shared outside `src/days`, its inputs, and the expected answers.
+/// Editor swap and lock files are ignored.
+fn is_relevant(day: u8, path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if name.starts_with(['.', '#']) {
+        return false;
+    }
+    let dirs: Vec<&str> = path
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .filter_map(|dir| dir.as_os_str().to_str())
+        .collect();
+    match dirs[..] {
+        ["data"] => {
+            name == format!("day{day}_test")
+                || name == format!("day{day}_2_test")
+                || name == format!("day{day}.txt")
+                || name == "answers.toml"
+        }
+        ["src", "days"] => name == format!("day{day}.rs"),
+        ["src", ..] => name.ends_with(".rs"),
+        _ => false,
+    }
+}
+
+#[derive(Deserialize)]
+struct RunRecord {
+    part: Part,
+    answer: Option<String>,
+}
+
+/// Reads the answers from `run --format json` output
+///
+/// # Errors
+///
+/// Returns the `serde_json` error for a line that isn't a record
+pub fn read_records(kind: InputKind, json: &str) -> Result<Answers, serde_json::Error> {
+    json.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let record: RunRecord = serde_json::from_str(line)?;
+            let answer = record.answer.map_or(Answer::Unsolved, Answer::Solved);
+            Ok(((kind, record.part), answer))
+        })
+        .collect()
+}
+
+/// A line per answer, saying whether it's the expected one and how it's changed since the last
+/// run.  `previous` is `None` for the first run, which has nothing to compare against.
+pub fn describe_changes(
+    day: u8,
+    previous: Option<&Answers>,
+    current: &Answers,
+    manifest: &Manifest,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for kind in [InputKind::Sample, InputKind::Real] {
+        for part in [Part::One, Part::Two] {
+            let before = previous.and_then(|previous| previous.get(&(kind, part)));
+            let Some(answer) = current.get(&(kind, part)) else {
+                // Only worth mentioning if it used to work
+                if let Some(before) = before {
+                    lines.push(format!(
+                        "Day {day:>2}  {kind:6}  Part {part:3}  {:7}  no answer, was {before}",
+                        Verdict::Fail
+                    ));
+                }
+                continue;
+            };
+            let expected = manifest.expected(day, kind, part);
+            let verdict = verdict(expected.as_ref(), answer);
+            let mut notes = Vec::new();
+            if verdict == Verdict::Fail {
+                notes.push(match &expected {
+                    Some(Expected::Clear(expected)) => format!("expected {expected}"),
+                    _ => "doesn't match the hash".to_owned(),
+                });
+            }
+            match (previous, before) {
+                (None, _) => (),
+                (Some(_), None) => notes.push("new".to_owned()),
+                (Some(_), Some(before)) if before == answer => notes.push("unchanged".to_owned()),
+                (Some(_), Some(before)) => notes.push(format!("was {before}")),
+            }
+            let notes = if notes.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", notes.join(", "))
+            };
+            lines.push(format!(
+                "Day {day:>2}  {kind:6}  Part {part:3}  {verdict:7}  {answer}{notes}"
+            ));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("src/days/day7.rs", true)]
+    #[case("src/days/day8.rs", false)]
+    #[case("src/days/mod.rs", false)]
+    #[case("src/grid.rs", true)]
+    #[case("src/lib.rs", true)]
+    #[case("src/.grid.rs.swp", false)]
+    #[case("src/days/.#day7.rs", false)]
+    #[case("src/days/day7.rs~", false)]
+    #[case("data/day7_test", true)]
+    #[case("data/day7_2_test", true)]
+    #[case("data/day7.txt", true)]
+    #[case("data/day7.txt.partial", false)]
+    #[case("data/day17.txt", false)]
+    #[case("data/answers.toml", true)]
+    #[case("data/attempts.jsonl", false)]
+    #[case("templates/day.rs", false)]
+    #[case("day7.rs", false)]
+    fn test_is_relevant(#[case] path: &str, #[case] want: bool) {
+        assert_eq!(is_relevant(7, Path::new(path)), want);
+    }
+
+    #[rstest]
+    fn test_debounce() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debounce = Debounce::new(ms(300));
+        assert_eq!(debounce.remaining(start), None);
+        assert!(!debounce.ready(start + ms(1000)));
+
+        debounce.changed(start);
+        assert_eq!(debounce.remaining(start + ms(100)), Some(ms(200)));
+        // Another change starts the wait over
+        debounce.changed(start + ms(200));
+        assert!(!debounce.ready(start + ms(300)));
+        assert_eq!(debounce.remaining(start + ms(300)), Some(ms(200)));
+        assert!(debounce.ready(start + ms(500)));
+        // And it only fires once
+        assert!(!debounce.ready(start + ms(600)));
+        assert_eq!(debounce.remaining(start + ms(600)), None);
+    }
+
+    #[rstest]
+    fn test_read_records() {
+        let json = r#"{"day":7,"part":1,"answer":"21","parse_ns":1,"solve_ns":2,"input":"./data/day7_test","profile":"debug"}
+{"day":7,"part":2,"answer":null,"parse_ns":1,"solve_ns":2,"input":"./data/day7_test","profile":"debug"}
+"#;
+        let answers = read_records(InputKind::Sample, json).unwrap();
+        assert_eq!(
+            answers,
+            Answers::from([
+                (
+                    (InputKind::Sample, Part::One),
+                    Answer::Solved("21".to_owned())
+                ),
+                ((InputKind::Sample, Part::Two), Answer::Unsolved),
+            ])
+        );
+        assert!(read_records(InputKind::Real, "Day 7 Part One Result: 21").is_err());
+    }
+
+    #[rstest]
+    fn test_describe_changes() {
+        let path = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let mut manifest = Manifest::load(path.to_str().unwrap()).unwrap();
+        manifest.set(
+            7,
+            InputKind::Sample,
+            Part::One,
+            &Expected::Clear("21".into()),
+        );
+        manifest.set(
+            7,
+            InputKind::Sample,
+            Part::Two,
+            &Expected::Clear("40".into()),
+        );
+        manifest.set(7, InputKind::Real, Part::One, &Expected::hashed("1234"));
+        let solved = |answer: &str| Answer::Solved(answer.to_owned());
+
+        let first = Answers::from([
+            ((InputKind::Sample, Part::One), solved("21")),
+            ((InputKind::Sample, Part::Two), solved("39")),
+            ((InputKind::Real, Part::One), solved("1234")),
+        ]);
+        assert_eq!(
+            describe_changes(7, None, &first, &manifest),
+            [
+                "Day  7  sample  Part One  pass     21",
+                "Day  7  sample  Part Two  FAIL     39 (expected 40)",
+                "Day  7  input   Part One  pass     1234",
+            ]
+        );
+
+        let second = Answers::from([
+            ((InputKind::Sample, Part::One), solved("21")),
+            ((InputKind::Sample, Part::Two), solved("40")),
+            ((InputKind::Real, Part::Two), Answer::Unsolved),
+        ]);
+        assert_eq!(
+            describe_changes(7, Some(&first), &second, &manifest),
+            [
+                "Day  7  sample  Part One  pass     21 (unchanged)",
+                "Day  7  sample  Part Two  pass     40 (was 39)",
+                "Day  7  input   Part One  FAIL     no answer, was 1234",
+                "Day  7  input   Part Two  missing  unsolved (new)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+fn parse_input(input: &Input) -> Result<Vec<String>, AocError> {
+    let _t = Timer::start(format!("Parsing file: {}", input.path()));
+    Ok(input.lines().map(str::to_owned).collect())
+}
+
+fn part_one(_data: &[String]) -> Unsolved {
+    let _t = Timer::start("Part One");
+    Unsolved
+}
+
+fn part_two(_data: &[String]) -> Unsolved {
+    let _t = Timer::start("Part Two");
+    Unsolved
+}
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{day}};
+    type Input = Vec<String>;
+    type PartOne = Unsolved;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &Input) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Unsolved {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Unsolved {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_part_one_from_sample_data() {
+        let data = parse_input(&Input::read("./data/day{{day}}_test").unwrap()).unwrap();
+        assert_eq!(part_one(&data).into_answer(), Answer::Unsolved);
+    }
+
+    #[rstest]
+    fn test_part_two_from_sample_data() {
+        let data = parse_input(&Input::read("./data/day{{day}}_test").unwrap()).unwrap();
+        assert_eq!(part_two(&data).into_answer(), Answer::Unsolved);
+    }
+}